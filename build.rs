@@ -1,13 +1,21 @@
-//use std::env;
+use std::env;
+
+/// 代码中按版本启用的 feature，未检测到对应版本时也需要声明，避免 unexpected_cfgs 警告
+const VERSION_FEATURES: [&str; 3] = ["ffmpeg_5_1", "ffmpeg_6_0", "ffmpeg_6_1"];
 
 fn main() {
-    println!("Starting build process...");
-    // for (name, _value) in env::vars() {
-    //     if name.starts_with("DEP_FFMPEG_") {
-    //         println!(
-    //             r#"cargo:rustc-cfg=feature="{}""#,
-    //             name["DEP_FFMPEG_".len()..name.len()].to_lowercase()
-    //         );
-    //     }
-    // }
-}
\ No newline at end of file
+    // ffmpeg-sys-next 通过 links 元数据给出 FFmpeg 版本，如 DEP_FFMPEG_FFMPEG_6_0=true，转换为同名 feature
+    for (name, value) in env::vars() {
+        if name.starts_with("DEP_FFMPEG_") {
+            let feature = name["DEP_FFMPEG_".len()..name.len()].to_lowercase();
+            if value == "true" {
+                println!(r#"cargo:rustc-cfg=feature="{}""#, feature);
+            }
+            println!(r#"cargo:rustc-check-cfg=cfg(feature, values("{}"))"#, feature);
+        }
+    }
+
+    for feature in VERSION_FEATURES {
+        println!(r#"cargo:rustc-check-cfg=cfg(feature, values("{}"))"#, feature);
+    }
+}
@@ -124,11 +124,12 @@ impl Iterator for ChannelLayoutIter {
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
-            if self.ptr == ptr::null() {
+            // 以声道数为 0 的布局结尾
+            if self.ptr == ptr::null() || (*self.ptr).nb_channels == 0 {
                 return None;
             }
 
-            let layout = ChannelLayout::wrap(self.ptr as *mut _);
+            let layout = ChannelLayout::from_ptr(self.ptr);
             self.ptr = self.ptr.offset(1);
             Some(layout)
         }
@@ -1,11 +1,12 @@
 use std::ffi::CStr;
 use std::str::from_utf8_unchecked;
 
-use anyhow::{anyhow, Result};
+use crate::util::error::{Error, Result};
 use crate::codec::audio::Audio;
 use crate::codec::capabilities::Capabilities;
 
 use crate::codec::codec_id::CodecId;
+use crate::codec::descriptor::Descriptor;
 use crate::ffi;
 use crate::util::media;
 
@@ -47,7 +48,7 @@ impl Codec {
         if self.medium() == media::Type::Audio {
             Ok(Audio::new(self))
         } else {
-            Err(anyhow!("not audio"))
+            Err(Error::InvalidMediaType)
         }
     }
 
@@ -79,6 +80,11 @@ impl Codec {
         unsafe { CodecId::from((*self.as_ptr()).id) }
     }
 
+    /// 编解码器描述，包含是否无损、是否只有帧内编码等特性
+    pub fn descriptor(&self) -> Option<Descriptor> {
+        Descriptor::find(self.id())
+    }
+
     /// 用于描述编解码器的能力和特性
     pub fn capabilities(&self) -> Capabilities {
         unsafe { Capabilities::from_bits_truncate((*self.as_ptr()).capabilities as u32) }
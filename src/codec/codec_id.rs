@@ -4,10 +4,227 @@ use std::str::from_utf8_unchecked;
 use crate::ffi::{self, AVCodecID};
 use crate::util::media;
 
+/// 编解码器 ID，较新 FFmpeg 版本中的取值按版本启用，当前版本未列出的取值保存在 Other 中
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CodecId {
     NONE,
+
+    // 视频编解码器
+    MPEG1VIDEO,
+    MPEG2VIDEO,
+    H261,
+    H263,
+    RV10,
+    RV20,
+    MJPEG,
+    MJPEGB,
+    LJPEG,
+    SP5X,
+    JPEGLS,
+    MPEG4,
+    RAWVIDEO,
+    MSMPEG4V1,
+    MSMPEG4V2,
+    MSMPEG4V3,
+    WMV1,
+    WMV2,
+    H263P,
+    H263I,
+    FLV1,
+    SVQ1,
+    SVQ3,
+    DVVIDEO,
+    HUFFYUV,
+    CYUV,
+    H264,
+    INDEO3,
+    VP3,
+    THEORA,
+    ASV1,
+    ASV2,
+    FFV1,
+    XM4,
+    VCR1,
+    CLJR,
+    MDEC,
+    ROQ,
+    INTERPLAY_VIDEO,
+    XAN_WC3,
+    XAN_WC4,
+    RPZA,
+    CINEPAK,
+    WS_VQA,
+    MSRLE,
+    MSVIDEO1,
+    IDCIN,
+    BPS8,
+    SMC,
+    FLIC,
+    TRUEMOTION1,
+    VMDVIDEO,
+    MSZH,
+    ZLIB,
+    QTRLE,
+    TSCC,
+    ULTI,
+    QDRAW,
+    VIXL,
+    QPEG,
+    PNG,
+    PPM,
+    PBM,
+    PGM,
+    PGMYUV,
+    PAM,
+    FFVHUFF,
+    RV30,
+    RV40,
+    VC1,
+    WMV3,
+    LOCO,
+    WNV1,
+    AASC,
+    INDEO2,
+    FRAPS,
+    TRUEMOTION2,
+    BMP,
+    CSCD,
+    MMVIDEO,
+    ZMBV,
+    AVS,
+    SMACKVIDEO,
+    NUV,
+    KMVC,
+    FLASHSV,
+    CAVS,
+    JPEG2000,
+    VMNC,
+    VP5,
+    VP6,
+    VP6F,
+    TARGA,
+    DSICINVIDEO,
+    TIERTEXSEQVIDEO,
+    TIFF,
+    GIF,
+    DXA,
+    DNXHD,
+    THP,
+    SGI,
+    C93,
+    BETHSOFTVID,
+    PTX,
+    TXD,
+    VP6A,
+    AMV,
+    VB,
+    PCX,
+    SUNRAST,
+    INDEO4,
+    INDEO5,
+    MIMIC,
+    RL2,
+    ESCAPE124,
+    DIRAC,
+    BFI,
+    CMV,
+    MOTIONPIXELS,
+    TGV,
+    TGQ,
+    TQI,
+    AURA,
+    AURA2,
+    V210X,
+    TMV,
+    V210,
+    DPX,
+    MAD,
+    FRWU,
+    FLASHSV2,
+    CDGRAPHICS,
+    R210,
+    ANM,
+    BINKVIDEO,
+    IFF_ILBM,
+    KGV1,
+    YOP,
+    VP8,
+    PICTOR,
+    ANSI,
+    A64_MULTI,
+    A64_MULTI5,
+    R10K,
+    MXPEG,
+    LAGARITH,
+    PRORES,
+    JV,
+    DFA,
+    WMV3IMAGE,
+    VC1IMAGE,
+    UTVIDEO,
+    BMV_VIDEO,
+    VBLE,
+    DXTORY,
+    V410,
+    XWD,
+    CDXL,
+    XBM,
+    ZEROCODEC,
+    MSS1,
+    MSA1,
+    TSCC2,
+    MTS2,
+    CLLC,
+    MSS2,
+    VP9,
+    AIC,
+    ESCAPE130,
+    G2M,
+    WEBP,
+    HNM4_VIDEO,
+    HEVC,
+    FIC,
+    ALIAS_PIX,
+    BRENDER_PIX,
+    PAF_VIDEO,
+    EXR,
+    VP7,
+    SANM,
+    SGIRLE,
+    MVC1,
+    MVC2,
+    HQX,
+    TDSC,
+    HQ_HQA,
+    HAP,
+    DDS,
+    DXV,
+    SCREENPRESSO,
+    RSCC,
+    Y41P,
+    AVRP,
+    V012,
+    AVUI,
+    AYUV,
+    TARGA_Y216,
+    V308,
+    V408,
+    YUV4,
+    AVRN,
+    CPIA,
+    XFACE,
+    SNOW,
+    SMVJPEG,
+    APNG,
+    DAALA,
+    CFHD,
+    TRUEMOTION2RT,
+    M101,
+    MAGICYUV,
+    SHEERVIDEO,
+    YLC,
+
     // pcm 编解码器
     PCM_S16LE,
     PCM_S16BE,
@@ -40,13 +257,341 @@ pub enum CodecId {
     PCM_S24LE_PLANAR,
     PCM_S32LE_PLANAR,
     PCM_S16BE_PLANAR,
-
     PCM_S64LE,
     PCM_S64BE,
 
+    // adpcm 编解码器
+    ADPCM_IMA_QT,
+    ADPCM_IMA_WAV,
+    ADPCM_IMA_DK3,
+    ADPCM_IMA_DK4,
+    ADPCM_IMA_WS,
+    ADPCM_IMA_SMJPEG,
+    ADPCM_MS,
+    ADPCM_4XM,
+    ADPCM_XA,
+    ADPCM_ADX,
+    ADPCM_EA,
+    ADPCM_G726,
+    ADPCM_CT,
+    ADPCM_SWF,
+    ADPCM_YAMAHA,
+    ADPCM_SBPRO_4,
+    ADPCM_SBPRO_3,
+    ADPCM_SBPRO_2,
+    ADPCM_THP,
+    ADPCM_IMA_AMV,
+    ADPCM_EA_R1,
+    ADPCM_EA_R3,
+    ADPCM_EA_R2,
+    ADPCM_IMA_EA_SEAD,
+    ADPCM_IMA_EA_EACS,
+    ADPCM_EA_XAS,
+    ADPCM_EA_MAXIS_XA,
+    ADPCM_IMA_ISS,
+    ADPCM_G722,
+    ADPCM_IMA_APC,
+    ADPCM_VIMA,
+    ADPCM_AFC,
+    ADPCM_IMA_OKI,
+    ADPCM_DTK,
+    ADPCM_IMA_RAD,
+    ADPCM_G726LE,
+    ADPCM_THP_LE,
+    ADPCM_PSX,
+    ADPCM_AICA,
+    ADPCM_IMA_DAT4,
+    ADPCM_MTAF,
+
+    // amr 编解码器
+    AMR_NB,
+    AMR_WB,
+
+    // RealAudio 编解码器
+    RA_144,
+    RA_288,
+
+    // dpcm 编解码器
+    ROQ_DPCM,
+    INTERPLAY_DPCM,
+    XAN_DPCM,
+    SOL_DPCM,
+    SDX2_DPCM,
+
+    // 音频编解码器
+    MP2,
+    MP3,
+    AAC,
+    AC3,
+    DTS,
+    VORBIS,
+    DVAUDIO,
+    WMAV1,
+    WMAV2,
+    MACE3,
+    MACE6,
+    VMDAUDIO,
+    FLAC,
+    MP3ADU,
+    MP3ON4,
+    SHORTEN,
+    ALAC,
+    WESTWOOD_SND1,
+    GSM,
+    QDM2,
+    COOK,
+    TRUESPEECH,
+    TTA,
+    SMACKAUDIO,
+    QCELP,
+    WAVPACK,
+    DSICINAUDIO,
+    IMC,
+    MUSEPACK7,
+    MLP,
+    GSM_MS,
+    ATRAC3,
+    APE,
+    NELLYMOSER,
+    MUSEPACK8,
+    SPEEX,
+    WMAVOICE,
+    WMAPRO,
+    WMALOSSLESS,
+    ATRAC3P,
+    EAC3,
+    SIPR,
+    MP1,
+    TWINVQ,
+    TRUEHD,
+    MP4ALS,
+    ATRAC1,
+    BINKAUDIO_RDFT,
+    BINKAUDIO_DCT,
+    AAC_LATM,
+    QDMC,
+    CELT,
+    G723_1,
+    G729,
+    SVX_EXP8,
+    SVX_FIB8,
+    BMV_AUDIO,
+    RALF,
+    IAC,
+    ILBC,
+    OPUS,
+    COMFORT_NOISE,
+    TAK,
+    METASOUND,
+    PAF_AUDIO,
+    ON2AVC,
+    DSS_SP,
+    CODEC2,
+    FFWAVESYNTH,
+    SONIC,
+    SONIC_LS,
+    EVRC,
+    SMV,
+    DSD_LSBF,
+    DSD_MSBF,
+    DSD_LSBF_PLANAR,
+    DSD_MSBF_PLANAR,
+    _4GV,
+    INTERPLAY_ACM,
+    XMA1,
+    XMA2,
+    DST,
+
+    // 字幕编解码器
+    DVD_SUBTITLE,
+    DVB_SUBTITLE,
+    TEXT,
+    XSUB,
+    SSA,
+    MOV_TEXT,
+    HDMV_PGS_SUBTITLE,
+    DVB_TELETEXT,
+    SRT,
+    MICRODVD,
+    EIA_608,
+    JACOSUB,
+    SAMI,
+    REALTEXT,
+    STL,
+    SUBVIEWER1,
+    SUBVIEWER,
+    SUBRIP,
+    WEBVTT,
+    MPL2,
+    VPLAYER,
+    PJS,
+    ASS,
+    HDMV_TEXT_SUBTITLE,
+
+    // 其他编解码器（一般用于附件）以及伪编解码器
+    TTF,
+    SCTE_35,
+    BINTEXT,
+    XBIN,
+    IDF,
+    OTF,
+    SMPTE_KLV,
+    DVD_NAV,
+    TIMED_ID3,
+    BIN_DATA,
+    PROBE,
+    MPEG2TS,
+    MPEG4SYSTEMS,
+    FFMETADATA,
+    WRAPPED_AVFRAME,
+    PSD,
+    PIXLET,
+    SPEEDHQ,
+    FMVC,
+    CLEARVIDEO,
+    SCPR,
+    XPM,
+    AV1,
+    PCM_F16LE,
+    PCM_F24LE,
+    ATRAC3AL,
+    ATRAC3PAL,
+    BITPACKED,
+    MSCC,
+    SRGC,
+    SVG,
+    GDV,
+    FITS,
+    GREMLIN_DPCM,
+    DOLBY_E,
+    APTX,
+    APTX_HD,
+    SBC,
+    AVS2,
+    IMM4,
+    PROSUMER,
+    MWSC,
+    WCMV,
+    RASC,
+    PCM_VIDC,
+    ATRAC9,
+    TTML,
+    HYMT,
+    ARBC,
+    AGM,
+    LSCR,
+    VP4,
+    ADPCM_AGM,
+    HCOM,
+    ARIB_CAPTION,
+    IMM5,
+    MVDV,
+    MVHA,
+    CDTOONS,
+    MV30,
+    NOTCHLC,
+    PFM,
+    ADPCM_ARGO,
+    ADPCM_IMA_SSI,
+    ADPCM_ZORK,
+    ADPCM_IMA_APM,
+    ADPCM_IMA_ALP,
+    ADPCM_IMA_MTF,
+    ADPCM_IMA_CUNNING,
+    DERF_DPCM,
+    ACELP_KELVIN,
+    MPEGH_3D_AUDIO,
+    SIREN,
+    HCA,
+    EPG,
+    PGX,
+    AVS3,
+    MSP2,
+    VVC,
+    MOBICLIP,
+    PHOTOCD,
+    IPU,
+    ARGO,
+    CRI,
+    SIMBIOSIS_IMX,
+    SGA_VIDEO,
+    PCM_SGA,
+    ADPCM_IMA_MOFLEX,
+    FASTAUDIO,
+    GEM,
+    ADPCM_IMA_ACORN,
+    MSNSIREN,
+    VBN,
+    JPEGXL,
+    QOI,
+    PHM,
+    DFPWM,
+    #[cfg(feature = "ffmpeg_6_0")]
+    RADIANCE_HDR,
+    #[cfg(feature = "ffmpeg_6_0")]
+    WBMP,
+    #[cfg(feature = "ffmpeg_6_0")]
+    MEDIA100,
+    #[cfg(feature = "ffmpeg_6_0")]
+    VQC,
+    #[cfg(feature = "ffmpeg_6_0")]
+    ADPCM_XMD,
+    #[cfg(feature = "ffmpeg_6_0")]
+    WADY_DPCM,
+    #[cfg(feature = "ffmpeg_6_0")]
+    CBD2_DPCM,
+    #[cfg(feature = "ffmpeg_6_0")]
+    BONK,
+    #[cfg(feature = "ffmpeg_6_0")]
+    MISC4,
+    #[cfg(feature = "ffmpeg_6_0")]
+    APAC,
+    #[cfg(feature = "ffmpeg_6_0")]
+    FTR,
+    #[cfg(feature = "ffmpeg_6_0")]
+    WAVARC,
+    #[cfg(feature = "ffmpeg_6_0")]
+    RKA,
+    #[cfg(feature = "ffmpeg_6_0")]
+    VNULL,
+    #[cfg(feature = "ffmpeg_6_0")]
+    ANULL,
+    #[cfg(feature = "ffmpeg_6_1")]
+    PDV,
+    #[cfg(feature = "ffmpeg_6_1")]
+    EVC,
+    #[cfg(feature = "ffmpeg_6_1")]
+    RTV1,
+    #[cfg(feature = "ffmpeg_6_1")]
+    VMIX,
+    #[cfg(feature = "ffmpeg_6_1")]
+    AC4,
+    #[cfg(feature = "ffmpeg_6_1")]
+    SMPTE_2038,
+    #[cfg(feature = "ffmpeg_6_1")]
+    OSQ,
+
+    /// 未列出的 AVCodecID 取值，只能由 AVCodecID 转换得到
+    Other(UnknownCodecId),
+}
+
+/// 未列出的 AVCodecID，不能在外部构造，保证转换回 AVCodecID 时取值有效
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownCodecId(AVCodecID);
+
+impl UnknownCodecId {
+    /// 原始的 AVCodecID 取值
+    pub fn value(&self) -> i32 {
+        self.0 as i32
+    }
 }
 
 impl CodecId {
+    /// HEVC 的别名
+    pub const H265: CodecId = CodecId::HEVC;
+    /// IFF_ILBM 的别名
+    pub const IFF_BYTERUN1: CodecId = CodecId::IFF_ILBM;
+
     pub fn medium(&self) -> media::Type {
         unsafe { media::Type::from(ffi::avcodec_get_type((*self).into())) }
     }
@@ -60,6 +605,223 @@ impl From<ffi::AVCodecID> for CodecId {
     fn from(value: ffi::AVCodecID) -> Self {
         match value {
             ffi::AVCodecID::AV_CODEC_ID_NONE => CodecId::NONE,
+
+            // 视频编解码器
+            ffi::AVCodecID::AV_CODEC_ID_MPEG1VIDEO => CodecId::MPEG1VIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_MPEG2VIDEO => CodecId::MPEG2VIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_H261 => CodecId::H261,
+            ffi::AVCodecID::AV_CODEC_ID_H263 => CodecId::H263,
+            ffi::AVCodecID::AV_CODEC_ID_RV10 => CodecId::RV10,
+            ffi::AVCodecID::AV_CODEC_ID_RV20 => CodecId::RV20,
+            ffi::AVCodecID::AV_CODEC_ID_MJPEG => CodecId::MJPEG,
+            ffi::AVCodecID::AV_CODEC_ID_MJPEGB => CodecId::MJPEGB,
+            ffi::AVCodecID::AV_CODEC_ID_LJPEG => CodecId::LJPEG,
+            ffi::AVCodecID::AV_CODEC_ID_SP5X => CodecId::SP5X,
+            ffi::AVCodecID::AV_CODEC_ID_JPEGLS => CodecId::JPEGLS,
+            ffi::AVCodecID::AV_CODEC_ID_MPEG4 => CodecId::MPEG4,
+            ffi::AVCodecID::AV_CODEC_ID_RAWVIDEO => CodecId::RAWVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_MSMPEG4V1 => CodecId::MSMPEG4V1,
+            ffi::AVCodecID::AV_CODEC_ID_MSMPEG4V2 => CodecId::MSMPEG4V2,
+            ffi::AVCodecID::AV_CODEC_ID_MSMPEG4V3 => CodecId::MSMPEG4V3,
+            ffi::AVCodecID::AV_CODEC_ID_WMV1 => CodecId::WMV1,
+            ffi::AVCodecID::AV_CODEC_ID_WMV2 => CodecId::WMV2,
+            ffi::AVCodecID::AV_CODEC_ID_H263P => CodecId::H263P,
+            ffi::AVCodecID::AV_CODEC_ID_H263I => CodecId::H263I,
+            ffi::AVCodecID::AV_CODEC_ID_FLV1 => CodecId::FLV1,
+            ffi::AVCodecID::AV_CODEC_ID_SVQ1 => CodecId::SVQ1,
+            ffi::AVCodecID::AV_CODEC_ID_SVQ3 => CodecId::SVQ3,
+            ffi::AVCodecID::AV_CODEC_ID_DVVIDEO => CodecId::DVVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_HUFFYUV => CodecId::HUFFYUV,
+            ffi::AVCodecID::AV_CODEC_ID_CYUV => CodecId::CYUV,
+            ffi::AVCodecID::AV_CODEC_ID_H264 => CodecId::H264,
+            ffi::AVCodecID::AV_CODEC_ID_INDEO3 => CodecId::INDEO3,
+            ffi::AVCodecID::AV_CODEC_ID_VP3 => CodecId::VP3,
+            ffi::AVCodecID::AV_CODEC_ID_THEORA => CodecId::THEORA,
+            ffi::AVCodecID::AV_CODEC_ID_ASV1 => CodecId::ASV1,
+            ffi::AVCodecID::AV_CODEC_ID_ASV2 => CodecId::ASV2,
+            ffi::AVCodecID::AV_CODEC_ID_FFV1 => CodecId::FFV1,
+            ffi::AVCodecID::AV_CODEC_ID_4XM => CodecId::XM4,
+            ffi::AVCodecID::AV_CODEC_ID_VCR1 => CodecId::VCR1,
+            ffi::AVCodecID::AV_CODEC_ID_CLJR => CodecId::CLJR,
+            ffi::AVCodecID::AV_CODEC_ID_MDEC => CodecId::MDEC,
+            ffi::AVCodecID::AV_CODEC_ID_ROQ => CodecId::ROQ,
+            ffi::AVCodecID::AV_CODEC_ID_INTERPLAY_VIDEO => CodecId::INTERPLAY_VIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_XAN_WC3 => CodecId::XAN_WC3,
+            ffi::AVCodecID::AV_CODEC_ID_XAN_WC4 => CodecId::XAN_WC4,
+            ffi::AVCodecID::AV_CODEC_ID_RPZA => CodecId::RPZA,
+            ffi::AVCodecID::AV_CODEC_ID_CINEPAK => CodecId::CINEPAK,
+            ffi::AVCodecID::AV_CODEC_ID_WS_VQA => CodecId::WS_VQA,
+            ffi::AVCodecID::AV_CODEC_ID_MSRLE => CodecId::MSRLE,
+            ffi::AVCodecID::AV_CODEC_ID_MSVIDEO1 => CodecId::MSVIDEO1,
+            ffi::AVCodecID::AV_CODEC_ID_IDCIN => CodecId::IDCIN,
+            ffi::AVCodecID::AV_CODEC_ID_8BPS => CodecId::BPS8,
+            ffi::AVCodecID::AV_CODEC_ID_SMC => CodecId::SMC,
+            ffi::AVCodecID::AV_CODEC_ID_FLIC => CodecId::FLIC,
+            ffi::AVCodecID::AV_CODEC_ID_TRUEMOTION1 => CodecId::TRUEMOTION1,
+            ffi::AVCodecID::AV_CODEC_ID_VMDVIDEO => CodecId::VMDVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_MSZH => CodecId::MSZH,
+            ffi::AVCodecID::AV_CODEC_ID_ZLIB => CodecId::ZLIB,
+            ffi::AVCodecID::AV_CODEC_ID_QTRLE => CodecId::QTRLE,
+            ffi::AVCodecID::AV_CODEC_ID_TSCC => CodecId::TSCC,
+            ffi::AVCodecID::AV_CODEC_ID_ULTI => CodecId::ULTI,
+            ffi::AVCodecID::AV_CODEC_ID_QDRAW => CodecId::QDRAW,
+            ffi::AVCodecID::AV_CODEC_ID_VIXL => CodecId::VIXL,
+            ffi::AVCodecID::AV_CODEC_ID_QPEG => CodecId::QPEG,
+            ffi::AVCodecID::AV_CODEC_ID_PNG => CodecId::PNG,
+            ffi::AVCodecID::AV_CODEC_ID_PPM => CodecId::PPM,
+            ffi::AVCodecID::AV_CODEC_ID_PBM => CodecId::PBM,
+            ffi::AVCodecID::AV_CODEC_ID_PGM => CodecId::PGM,
+            ffi::AVCodecID::AV_CODEC_ID_PGMYUV => CodecId::PGMYUV,
+            ffi::AVCodecID::AV_CODEC_ID_PAM => CodecId::PAM,
+            ffi::AVCodecID::AV_CODEC_ID_FFVHUFF => CodecId::FFVHUFF,
+            ffi::AVCodecID::AV_CODEC_ID_RV30 => CodecId::RV30,
+            ffi::AVCodecID::AV_CODEC_ID_RV40 => CodecId::RV40,
+            ffi::AVCodecID::AV_CODEC_ID_VC1 => CodecId::VC1,
+            ffi::AVCodecID::AV_CODEC_ID_WMV3 => CodecId::WMV3,
+            ffi::AVCodecID::AV_CODEC_ID_LOCO => CodecId::LOCO,
+            ffi::AVCodecID::AV_CODEC_ID_WNV1 => CodecId::WNV1,
+            ffi::AVCodecID::AV_CODEC_ID_AASC => CodecId::AASC,
+            ffi::AVCodecID::AV_CODEC_ID_INDEO2 => CodecId::INDEO2,
+            ffi::AVCodecID::AV_CODEC_ID_FRAPS => CodecId::FRAPS,
+            ffi::AVCodecID::AV_CODEC_ID_TRUEMOTION2 => CodecId::TRUEMOTION2,
+            ffi::AVCodecID::AV_CODEC_ID_BMP => CodecId::BMP,
+            ffi::AVCodecID::AV_CODEC_ID_CSCD => CodecId::CSCD,
+            ffi::AVCodecID::AV_CODEC_ID_MMVIDEO => CodecId::MMVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_ZMBV => CodecId::ZMBV,
+            ffi::AVCodecID::AV_CODEC_ID_AVS => CodecId::AVS,
+            ffi::AVCodecID::AV_CODEC_ID_SMACKVIDEO => CodecId::SMACKVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_NUV => CodecId::NUV,
+            ffi::AVCodecID::AV_CODEC_ID_KMVC => CodecId::KMVC,
+            ffi::AVCodecID::AV_CODEC_ID_FLASHSV => CodecId::FLASHSV,
+            ffi::AVCodecID::AV_CODEC_ID_CAVS => CodecId::CAVS,
+            ffi::AVCodecID::AV_CODEC_ID_JPEG2000 => CodecId::JPEG2000,
+            ffi::AVCodecID::AV_CODEC_ID_VMNC => CodecId::VMNC,
+            ffi::AVCodecID::AV_CODEC_ID_VP5 => CodecId::VP5,
+            ffi::AVCodecID::AV_CODEC_ID_VP6 => CodecId::VP6,
+            ffi::AVCodecID::AV_CODEC_ID_VP6F => CodecId::VP6F,
+            ffi::AVCodecID::AV_CODEC_ID_TARGA => CodecId::TARGA,
+            ffi::AVCodecID::AV_CODEC_ID_DSICINVIDEO => CodecId::DSICINVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_TIERTEXSEQVIDEO => CodecId::TIERTEXSEQVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_TIFF => CodecId::TIFF,
+            ffi::AVCodecID::AV_CODEC_ID_GIF => CodecId::GIF,
+            ffi::AVCodecID::AV_CODEC_ID_DXA => CodecId::DXA,
+            ffi::AVCodecID::AV_CODEC_ID_DNXHD => CodecId::DNXHD,
+            ffi::AVCodecID::AV_CODEC_ID_THP => CodecId::THP,
+            ffi::AVCodecID::AV_CODEC_ID_SGI => CodecId::SGI,
+            ffi::AVCodecID::AV_CODEC_ID_C93 => CodecId::C93,
+            ffi::AVCodecID::AV_CODEC_ID_BETHSOFTVID => CodecId::BETHSOFTVID,
+            ffi::AVCodecID::AV_CODEC_ID_PTX => CodecId::PTX,
+            ffi::AVCodecID::AV_CODEC_ID_TXD => CodecId::TXD,
+            ffi::AVCodecID::AV_CODEC_ID_VP6A => CodecId::VP6A,
+            ffi::AVCodecID::AV_CODEC_ID_AMV => CodecId::AMV,
+            ffi::AVCodecID::AV_CODEC_ID_VB => CodecId::VB,
+            ffi::AVCodecID::AV_CODEC_ID_PCX => CodecId::PCX,
+            ffi::AVCodecID::AV_CODEC_ID_SUNRAST => CodecId::SUNRAST,
+            ffi::AVCodecID::AV_CODEC_ID_INDEO4 => CodecId::INDEO4,
+            ffi::AVCodecID::AV_CODEC_ID_INDEO5 => CodecId::INDEO5,
+            ffi::AVCodecID::AV_CODEC_ID_MIMIC => CodecId::MIMIC,
+            ffi::AVCodecID::AV_CODEC_ID_RL2 => CodecId::RL2,
+            ffi::AVCodecID::AV_CODEC_ID_ESCAPE124 => CodecId::ESCAPE124,
+            ffi::AVCodecID::AV_CODEC_ID_DIRAC => CodecId::DIRAC,
+            ffi::AVCodecID::AV_CODEC_ID_BFI => CodecId::BFI,
+            ffi::AVCodecID::AV_CODEC_ID_CMV => CodecId::CMV,
+            ffi::AVCodecID::AV_CODEC_ID_MOTIONPIXELS => CodecId::MOTIONPIXELS,
+            ffi::AVCodecID::AV_CODEC_ID_TGV => CodecId::TGV,
+            ffi::AVCodecID::AV_CODEC_ID_TGQ => CodecId::TGQ,
+            ffi::AVCodecID::AV_CODEC_ID_TQI => CodecId::TQI,
+            ffi::AVCodecID::AV_CODEC_ID_AURA => CodecId::AURA,
+            ffi::AVCodecID::AV_CODEC_ID_AURA2 => CodecId::AURA2,
+            ffi::AVCodecID::AV_CODEC_ID_V210X => CodecId::V210X,
+            ffi::AVCodecID::AV_CODEC_ID_TMV => CodecId::TMV,
+            ffi::AVCodecID::AV_CODEC_ID_V210 => CodecId::V210,
+            ffi::AVCodecID::AV_CODEC_ID_DPX => CodecId::DPX,
+            ffi::AVCodecID::AV_CODEC_ID_MAD => CodecId::MAD,
+            ffi::AVCodecID::AV_CODEC_ID_FRWU => CodecId::FRWU,
+            ffi::AVCodecID::AV_CODEC_ID_FLASHSV2 => CodecId::FLASHSV2,
+            ffi::AVCodecID::AV_CODEC_ID_CDGRAPHICS => CodecId::CDGRAPHICS,
+            ffi::AVCodecID::AV_CODEC_ID_R210 => CodecId::R210,
+            ffi::AVCodecID::AV_CODEC_ID_ANM => CodecId::ANM,
+            ffi::AVCodecID::AV_CODEC_ID_BINKVIDEO => CodecId::BINKVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_IFF_ILBM => CodecId::IFF_ILBM,
+            ffi::AVCodecID::AV_CODEC_ID_KGV1 => CodecId::KGV1,
+            ffi::AVCodecID::AV_CODEC_ID_YOP => CodecId::YOP,
+            ffi::AVCodecID::AV_CODEC_ID_VP8 => CodecId::VP8,
+            ffi::AVCodecID::AV_CODEC_ID_PICTOR => CodecId::PICTOR,
+            ffi::AVCodecID::AV_CODEC_ID_ANSI => CodecId::ANSI,
+            ffi::AVCodecID::AV_CODEC_ID_A64_MULTI => CodecId::A64_MULTI,
+            ffi::AVCodecID::AV_CODEC_ID_A64_MULTI5 => CodecId::A64_MULTI5,
+            ffi::AVCodecID::AV_CODEC_ID_R10K => CodecId::R10K,
+            ffi::AVCodecID::AV_CODEC_ID_MXPEG => CodecId::MXPEG,
+            ffi::AVCodecID::AV_CODEC_ID_LAGARITH => CodecId::LAGARITH,
+            ffi::AVCodecID::AV_CODEC_ID_PRORES => CodecId::PRORES,
+            ffi::AVCodecID::AV_CODEC_ID_JV => CodecId::JV,
+            ffi::AVCodecID::AV_CODEC_ID_DFA => CodecId::DFA,
+            ffi::AVCodecID::AV_CODEC_ID_WMV3IMAGE => CodecId::WMV3IMAGE,
+            ffi::AVCodecID::AV_CODEC_ID_VC1IMAGE => CodecId::VC1IMAGE,
+            ffi::AVCodecID::AV_CODEC_ID_UTVIDEO => CodecId::UTVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_BMV_VIDEO => CodecId::BMV_VIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_VBLE => CodecId::VBLE,
+            ffi::AVCodecID::AV_CODEC_ID_DXTORY => CodecId::DXTORY,
+            ffi::AVCodecID::AV_CODEC_ID_V410 => CodecId::V410,
+            ffi::AVCodecID::AV_CODEC_ID_XWD => CodecId::XWD,
+            ffi::AVCodecID::AV_CODEC_ID_CDXL => CodecId::CDXL,
+            ffi::AVCodecID::AV_CODEC_ID_XBM => CodecId::XBM,
+            ffi::AVCodecID::AV_CODEC_ID_ZEROCODEC => CodecId::ZEROCODEC,
+            ffi::AVCodecID::AV_CODEC_ID_MSS1 => CodecId::MSS1,
+            ffi::AVCodecID::AV_CODEC_ID_MSA1 => CodecId::MSA1,
+            ffi::AVCodecID::AV_CODEC_ID_TSCC2 => CodecId::TSCC2,
+            ffi::AVCodecID::AV_CODEC_ID_MTS2 => CodecId::MTS2,
+            ffi::AVCodecID::AV_CODEC_ID_CLLC => CodecId::CLLC,
+            ffi::AVCodecID::AV_CODEC_ID_MSS2 => CodecId::MSS2,
+            ffi::AVCodecID::AV_CODEC_ID_VP9 => CodecId::VP9,
+            ffi::AVCodecID::AV_CODEC_ID_AIC => CodecId::AIC,
+            ffi::AVCodecID::AV_CODEC_ID_ESCAPE130 => CodecId::ESCAPE130,
+            ffi::AVCodecID::AV_CODEC_ID_G2M => CodecId::G2M,
+            ffi::AVCodecID::AV_CODEC_ID_WEBP => CodecId::WEBP,
+            ffi::AVCodecID::AV_CODEC_ID_HNM4_VIDEO => CodecId::HNM4_VIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_HEVC => CodecId::HEVC,
+            ffi::AVCodecID::AV_CODEC_ID_FIC => CodecId::FIC,
+            ffi::AVCodecID::AV_CODEC_ID_ALIAS_PIX => CodecId::ALIAS_PIX,
+            ffi::AVCodecID::AV_CODEC_ID_BRENDER_PIX => CodecId::BRENDER_PIX,
+            ffi::AVCodecID::AV_CODEC_ID_PAF_VIDEO => CodecId::PAF_VIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_EXR => CodecId::EXR,
+            ffi::AVCodecID::AV_CODEC_ID_VP7 => CodecId::VP7,
+            ffi::AVCodecID::AV_CODEC_ID_SANM => CodecId::SANM,
+            ffi::AVCodecID::AV_CODEC_ID_SGIRLE => CodecId::SGIRLE,
+            ffi::AVCodecID::AV_CODEC_ID_MVC1 => CodecId::MVC1,
+            ffi::AVCodecID::AV_CODEC_ID_MVC2 => CodecId::MVC2,
+            ffi::AVCodecID::AV_CODEC_ID_HQX => CodecId::HQX,
+            ffi::AVCodecID::AV_CODEC_ID_TDSC => CodecId::TDSC,
+            ffi::AVCodecID::AV_CODEC_ID_HQ_HQA => CodecId::HQ_HQA,
+            ffi::AVCodecID::AV_CODEC_ID_HAP => CodecId::HAP,
+            ffi::AVCodecID::AV_CODEC_ID_DDS => CodecId::DDS,
+            ffi::AVCodecID::AV_CODEC_ID_DXV => CodecId::DXV,
+            ffi::AVCodecID::AV_CODEC_ID_SCREENPRESSO => CodecId::SCREENPRESSO,
+            ffi::AVCodecID::AV_CODEC_ID_RSCC => CodecId::RSCC,
+            ffi::AVCodecID::AV_CODEC_ID_Y41P => CodecId::Y41P,
+            ffi::AVCodecID::AV_CODEC_ID_AVRP => CodecId::AVRP,
+            ffi::AVCodecID::AV_CODEC_ID_012V => CodecId::V012,
+            ffi::AVCodecID::AV_CODEC_ID_AVUI => CodecId::AVUI,
+            ffi::AVCodecID::AV_CODEC_ID_AYUV => CodecId::AYUV,
+            ffi::AVCodecID::AV_CODEC_ID_TARGA_Y216 => CodecId::TARGA_Y216,
+            ffi::AVCodecID::AV_CODEC_ID_V308 => CodecId::V308,
+            ffi::AVCodecID::AV_CODEC_ID_V408 => CodecId::V408,
+            ffi::AVCodecID::AV_CODEC_ID_YUV4 => CodecId::YUV4,
+            ffi::AVCodecID::AV_CODEC_ID_AVRN => CodecId::AVRN,
+            ffi::AVCodecID::AV_CODEC_ID_CPIA => CodecId::CPIA,
+            ffi::AVCodecID::AV_CODEC_ID_XFACE => CodecId::XFACE,
+            ffi::AVCodecID::AV_CODEC_ID_SNOW => CodecId::SNOW,
+            ffi::AVCodecID::AV_CODEC_ID_SMVJPEG => CodecId::SMVJPEG,
+            ffi::AVCodecID::AV_CODEC_ID_APNG => CodecId::APNG,
+            ffi::AVCodecID::AV_CODEC_ID_DAALA => CodecId::DAALA,
+            ffi::AVCodecID::AV_CODEC_ID_CFHD => CodecId::CFHD,
+            ffi::AVCodecID::AV_CODEC_ID_TRUEMOTION2RT => CodecId::TRUEMOTION2RT,
+            ffi::AVCodecID::AV_CODEC_ID_M101 => CodecId::M101,
+            ffi::AVCodecID::AV_CODEC_ID_MAGICYUV => CodecId::MAGICYUV,
+            ffi::AVCodecID::AV_CODEC_ID_SHEERVIDEO => CodecId::SHEERVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_YLC => CodecId::YLC,
+
+            // pcm 编解码器
             ffi::AVCodecID::AV_CODEC_ID_PCM_S16LE => CodecId::PCM_S16LE,
             ffi::AVCodecID::AV_CODEC_ID_PCM_S16BE => CodecId::PCM_S16BE,
             ffi::AVCodecID::AV_CODEC_ID_PCM_U16LE => CodecId::PCM_U16LE,
@@ -91,11 +853,322 @@ impl From<ffi::AVCodecID> for CodecId {
             ffi::AVCodecID::AV_CODEC_ID_PCM_S24LE_PLANAR => CodecId::PCM_S24LE_PLANAR,
             ffi::AVCodecID::AV_CODEC_ID_PCM_S32LE_PLANAR => CodecId::PCM_S32LE_PLANAR,
             ffi::AVCodecID::AV_CODEC_ID_PCM_S16BE_PLANAR => CodecId::PCM_S16BE_PLANAR,
-
             ffi::AVCodecID::AV_CODEC_ID_PCM_S64LE => CodecId::PCM_S64LE,
             ffi::AVCodecID::AV_CODEC_ID_PCM_S64BE => CodecId::PCM_S64BE,
 
-            _ => todo!(),
+            // adpcm 编解码器
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_QT => CodecId::ADPCM_IMA_QT,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_WAV => CodecId::ADPCM_IMA_WAV,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_DK3 => CodecId::ADPCM_IMA_DK3,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_DK4 => CodecId::ADPCM_IMA_DK4,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_WS => CodecId::ADPCM_IMA_WS,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_SMJPEG => CodecId::ADPCM_IMA_SMJPEG,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_MS => CodecId::ADPCM_MS,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_4XM => CodecId::ADPCM_4XM,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_XA => CodecId::ADPCM_XA,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_ADX => CodecId::ADPCM_ADX,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA => CodecId::ADPCM_EA,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_G726 => CodecId::ADPCM_G726,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_CT => CodecId::ADPCM_CT,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_SWF => CodecId::ADPCM_SWF,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_YAMAHA => CodecId::ADPCM_YAMAHA,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_SBPRO_4 => CodecId::ADPCM_SBPRO_4,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_SBPRO_3 => CodecId::ADPCM_SBPRO_3,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_SBPRO_2 => CodecId::ADPCM_SBPRO_2,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_THP => CodecId::ADPCM_THP,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_AMV => CodecId::ADPCM_IMA_AMV,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_R1 => CodecId::ADPCM_EA_R1,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_R3 => CodecId::ADPCM_EA_R3,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_R2 => CodecId::ADPCM_EA_R2,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_EA_SEAD => CodecId::ADPCM_IMA_EA_SEAD,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_EA_EACS => CodecId::ADPCM_IMA_EA_EACS,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_XAS => CodecId::ADPCM_EA_XAS,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_MAXIS_XA => CodecId::ADPCM_EA_MAXIS_XA,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_ISS => CodecId::ADPCM_IMA_ISS,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_G722 => CodecId::ADPCM_G722,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_APC => CodecId::ADPCM_IMA_APC,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_VIMA => CodecId::ADPCM_VIMA,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_AFC => CodecId::ADPCM_AFC,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_OKI => CodecId::ADPCM_IMA_OKI,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_DTK => CodecId::ADPCM_DTK,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_RAD => CodecId::ADPCM_IMA_RAD,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_G726LE => CodecId::ADPCM_G726LE,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_THP_LE => CodecId::ADPCM_THP_LE,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_PSX => CodecId::ADPCM_PSX,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_AICA => CodecId::ADPCM_AICA,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_DAT4 => CodecId::ADPCM_IMA_DAT4,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_MTAF => CodecId::ADPCM_MTAF,
+
+            // amr 编解码器
+            ffi::AVCodecID::AV_CODEC_ID_AMR_NB => CodecId::AMR_NB,
+            ffi::AVCodecID::AV_CODEC_ID_AMR_WB => CodecId::AMR_WB,
+
+            // RealAudio 编解码器
+            ffi::AVCodecID::AV_CODEC_ID_RA_144 => CodecId::RA_144,
+            ffi::AVCodecID::AV_CODEC_ID_RA_288 => CodecId::RA_288,
+
+            // dpcm 编解码器
+            ffi::AVCodecID::AV_CODEC_ID_ROQ_DPCM => CodecId::ROQ_DPCM,
+            ffi::AVCodecID::AV_CODEC_ID_INTERPLAY_DPCM => CodecId::INTERPLAY_DPCM,
+            ffi::AVCodecID::AV_CODEC_ID_XAN_DPCM => CodecId::XAN_DPCM,
+            ffi::AVCodecID::AV_CODEC_ID_SOL_DPCM => CodecId::SOL_DPCM,
+            ffi::AVCodecID::AV_CODEC_ID_SDX2_DPCM => CodecId::SDX2_DPCM,
+
+            // 音频编解码器
+            ffi::AVCodecID::AV_CODEC_ID_MP2 => CodecId::MP2,
+            ffi::AVCodecID::AV_CODEC_ID_MP3 => CodecId::MP3,
+            ffi::AVCodecID::AV_CODEC_ID_AAC => CodecId::AAC,
+            ffi::AVCodecID::AV_CODEC_ID_AC3 => CodecId::AC3,
+            ffi::AVCodecID::AV_CODEC_ID_DTS => CodecId::DTS,
+            ffi::AVCodecID::AV_CODEC_ID_VORBIS => CodecId::VORBIS,
+            ffi::AVCodecID::AV_CODEC_ID_DVAUDIO => CodecId::DVAUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_WMAV1 => CodecId::WMAV1,
+            ffi::AVCodecID::AV_CODEC_ID_WMAV2 => CodecId::WMAV2,
+            ffi::AVCodecID::AV_CODEC_ID_MACE3 => CodecId::MACE3,
+            ffi::AVCodecID::AV_CODEC_ID_MACE6 => CodecId::MACE6,
+            ffi::AVCodecID::AV_CODEC_ID_VMDAUDIO => CodecId::VMDAUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_FLAC => CodecId::FLAC,
+            ffi::AVCodecID::AV_CODEC_ID_MP3ADU => CodecId::MP3ADU,
+            ffi::AVCodecID::AV_CODEC_ID_MP3ON4 => CodecId::MP3ON4,
+            ffi::AVCodecID::AV_CODEC_ID_SHORTEN => CodecId::SHORTEN,
+            ffi::AVCodecID::AV_CODEC_ID_ALAC => CodecId::ALAC,
+            ffi::AVCodecID::AV_CODEC_ID_WESTWOOD_SND1 => CodecId::WESTWOOD_SND1,
+            ffi::AVCodecID::AV_CODEC_ID_GSM => CodecId::GSM,
+            ffi::AVCodecID::AV_CODEC_ID_QDM2 => CodecId::QDM2,
+            ffi::AVCodecID::AV_CODEC_ID_COOK => CodecId::COOK,
+            ffi::AVCodecID::AV_CODEC_ID_TRUESPEECH => CodecId::TRUESPEECH,
+            ffi::AVCodecID::AV_CODEC_ID_TTA => CodecId::TTA,
+            ffi::AVCodecID::AV_CODEC_ID_SMACKAUDIO => CodecId::SMACKAUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_QCELP => CodecId::QCELP,
+            ffi::AVCodecID::AV_CODEC_ID_WAVPACK => CodecId::WAVPACK,
+            ffi::AVCodecID::AV_CODEC_ID_DSICINAUDIO => CodecId::DSICINAUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_IMC => CodecId::IMC,
+            ffi::AVCodecID::AV_CODEC_ID_MUSEPACK7 => CodecId::MUSEPACK7,
+            ffi::AVCodecID::AV_CODEC_ID_MLP => CodecId::MLP,
+            ffi::AVCodecID::AV_CODEC_ID_GSM_MS => CodecId::GSM_MS,
+            ffi::AVCodecID::AV_CODEC_ID_ATRAC3 => CodecId::ATRAC3,
+            ffi::AVCodecID::AV_CODEC_ID_APE => CodecId::APE,
+            ffi::AVCodecID::AV_CODEC_ID_NELLYMOSER => CodecId::NELLYMOSER,
+            ffi::AVCodecID::AV_CODEC_ID_MUSEPACK8 => CodecId::MUSEPACK8,
+            ffi::AVCodecID::AV_CODEC_ID_SPEEX => CodecId::SPEEX,
+            ffi::AVCodecID::AV_CODEC_ID_WMAVOICE => CodecId::WMAVOICE,
+            ffi::AVCodecID::AV_CODEC_ID_WMAPRO => CodecId::WMAPRO,
+            ffi::AVCodecID::AV_CODEC_ID_WMALOSSLESS => CodecId::WMALOSSLESS,
+            ffi::AVCodecID::AV_CODEC_ID_ATRAC3P => CodecId::ATRAC3P,
+            ffi::AVCodecID::AV_CODEC_ID_EAC3 => CodecId::EAC3,
+            ffi::AVCodecID::AV_CODEC_ID_SIPR => CodecId::SIPR,
+            ffi::AVCodecID::AV_CODEC_ID_MP1 => CodecId::MP1,
+            ffi::AVCodecID::AV_CODEC_ID_TWINVQ => CodecId::TWINVQ,
+            ffi::AVCodecID::AV_CODEC_ID_TRUEHD => CodecId::TRUEHD,
+            ffi::AVCodecID::AV_CODEC_ID_MP4ALS => CodecId::MP4ALS,
+            ffi::AVCodecID::AV_CODEC_ID_ATRAC1 => CodecId::ATRAC1,
+            ffi::AVCodecID::AV_CODEC_ID_BINKAUDIO_RDFT => CodecId::BINKAUDIO_RDFT,
+            ffi::AVCodecID::AV_CODEC_ID_BINKAUDIO_DCT => CodecId::BINKAUDIO_DCT,
+            ffi::AVCodecID::AV_CODEC_ID_AAC_LATM => CodecId::AAC_LATM,
+            ffi::AVCodecID::AV_CODEC_ID_QDMC => CodecId::QDMC,
+            ffi::AVCodecID::AV_CODEC_ID_CELT => CodecId::CELT,
+            ffi::AVCodecID::AV_CODEC_ID_G723_1 => CodecId::G723_1,
+            ffi::AVCodecID::AV_CODEC_ID_G729 => CodecId::G729,
+            ffi::AVCodecID::AV_CODEC_ID_8SVX_EXP => CodecId::SVX_EXP8,
+            ffi::AVCodecID::AV_CODEC_ID_8SVX_FIB => CodecId::SVX_FIB8,
+            ffi::AVCodecID::AV_CODEC_ID_BMV_AUDIO => CodecId::BMV_AUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_RALF => CodecId::RALF,
+            ffi::AVCodecID::AV_CODEC_ID_IAC => CodecId::IAC,
+            ffi::AVCodecID::AV_CODEC_ID_ILBC => CodecId::ILBC,
+            ffi::AVCodecID::AV_CODEC_ID_OPUS => CodecId::OPUS,
+            ffi::AVCodecID::AV_CODEC_ID_COMFORT_NOISE => CodecId::COMFORT_NOISE,
+            ffi::AVCodecID::AV_CODEC_ID_TAK => CodecId::TAK,
+            ffi::AVCodecID::AV_CODEC_ID_METASOUND => CodecId::METASOUND,
+            ffi::AVCodecID::AV_CODEC_ID_PAF_AUDIO => CodecId::PAF_AUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_ON2AVC => CodecId::ON2AVC,
+            ffi::AVCodecID::AV_CODEC_ID_DSS_SP => CodecId::DSS_SP,
+            ffi::AVCodecID::AV_CODEC_ID_CODEC2 => CodecId::CODEC2,
+            ffi::AVCodecID::AV_CODEC_ID_FFWAVESYNTH => CodecId::FFWAVESYNTH,
+            ffi::AVCodecID::AV_CODEC_ID_SONIC => CodecId::SONIC,
+            ffi::AVCodecID::AV_CODEC_ID_SONIC_LS => CodecId::SONIC_LS,
+            ffi::AVCodecID::AV_CODEC_ID_EVRC => CodecId::EVRC,
+            ffi::AVCodecID::AV_CODEC_ID_SMV => CodecId::SMV,
+            ffi::AVCodecID::AV_CODEC_ID_DSD_LSBF => CodecId::DSD_LSBF,
+            ffi::AVCodecID::AV_CODEC_ID_DSD_MSBF => CodecId::DSD_MSBF,
+            ffi::AVCodecID::AV_CODEC_ID_DSD_LSBF_PLANAR => CodecId::DSD_LSBF_PLANAR,
+            ffi::AVCodecID::AV_CODEC_ID_DSD_MSBF_PLANAR => CodecId::DSD_MSBF_PLANAR,
+            ffi::AVCodecID::AV_CODEC_ID_4GV => CodecId::_4GV,
+            ffi::AVCodecID::AV_CODEC_ID_INTERPLAY_ACM => CodecId::INTERPLAY_ACM,
+            ffi::AVCodecID::AV_CODEC_ID_XMA1 => CodecId::XMA1,
+            ffi::AVCodecID::AV_CODEC_ID_XMA2 => CodecId::XMA2,
+            ffi::AVCodecID::AV_CODEC_ID_DST => CodecId::DST,
+
+            // 字幕编解码器
+            ffi::AVCodecID::AV_CODEC_ID_DVD_SUBTITLE => CodecId::DVD_SUBTITLE,
+            ffi::AVCodecID::AV_CODEC_ID_DVB_SUBTITLE => CodecId::DVB_SUBTITLE,
+            ffi::AVCodecID::AV_CODEC_ID_TEXT => CodecId::TEXT,
+            ffi::AVCodecID::AV_CODEC_ID_XSUB => CodecId::XSUB,
+            ffi::AVCodecID::AV_CODEC_ID_SSA => CodecId::SSA,
+            ffi::AVCodecID::AV_CODEC_ID_MOV_TEXT => CodecId::MOV_TEXT,
+            ffi::AVCodecID::AV_CODEC_ID_HDMV_PGS_SUBTITLE => CodecId::HDMV_PGS_SUBTITLE,
+            ffi::AVCodecID::AV_CODEC_ID_DVB_TELETEXT => CodecId::DVB_TELETEXT,
+            ffi::AVCodecID::AV_CODEC_ID_SRT => CodecId::SRT,
+            ffi::AVCodecID::AV_CODEC_ID_MICRODVD => CodecId::MICRODVD,
+            ffi::AVCodecID::AV_CODEC_ID_EIA_608 => CodecId::EIA_608,
+            ffi::AVCodecID::AV_CODEC_ID_JACOSUB => CodecId::JACOSUB,
+            ffi::AVCodecID::AV_CODEC_ID_SAMI => CodecId::SAMI,
+            ffi::AVCodecID::AV_CODEC_ID_REALTEXT => CodecId::REALTEXT,
+            ffi::AVCodecID::AV_CODEC_ID_STL => CodecId::STL,
+            ffi::AVCodecID::AV_CODEC_ID_SUBVIEWER1 => CodecId::SUBVIEWER1,
+            ffi::AVCodecID::AV_CODEC_ID_SUBVIEWER => CodecId::SUBVIEWER,
+            ffi::AVCodecID::AV_CODEC_ID_SUBRIP => CodecId::SUBRIP,
+            ffi::AVCodecID::AV_CODEC_ID_WEBVTT => CodecId::WEBVTT,
+            ffi::AVCodecID::AV_CODEC_ID_MPL2 => CodecId::MPL2,
+            ffi::AVCodecID::AV_CODEC_ID_VPLAYER => CodecId::VPLAYER,
+            ffi::AVCodecID::AV_CODEC_ID_PJS => CodecId::PJS,
+            ffi::AVCodecID::AV_CODEC_ID_ASS => CodecId::ASS,
+            ffi::AVCodecID::AV_CODEC_ID_HDMV_TEXT_SUBTITLE => CodecId::HDMV_TEXT_SUBTITLE,
+
+            // 其他编解码器（一般用于附件）以及伪编解码器
+            ffi::AVCodecID::AV_CODEC_ID_TTF => CodecId::TTF,
+            ffi::AVCodecID::AV_CODEC_ID_SCTE_35 => CodecId::SCTE_35,
+            ffi::AVCodecID::AV_CODEC_ID_BINTEXT => CodecId::BINTEXT,
+            ffi::AVCodecID::AV_CODEC_ID_XBIN => CodecId::XBIN,
+            ffi::AVCodecID::AV_CODEC_ID_IDF => CodecId::IDF,
+            ffi::AVCodecID::AV_CODEC_ID_OTF => CodecId::OTF,
+            ffi::AVCodecID::AV_CODEC_ID_SMPTE_KLV => CodecId::SMPTE_KLV,
+            ffi::AVCodecID::AV_CODEC_ID_DVD_NAV => CodecId::DVD_NAV,
+            ffi::AVCodecID::AV_CODEC_ID_TIMED_ID3 => CodecId::TIMED_ID3,
+            ffi::AVCodecID::AV_CODEC_ID_BIN_DATA => CodecId::BIN_DATA,
+            ffi::AVCodecID::AV_CODEC_ID_PROBE => CodecId::PROBE,
+            ffi::AVCodecID::AV_CODEC_ID_MPEG2TS => CodecId::MPEG2TS,
+            ffi::AVCodecID::AV_CODEC_ID_MPEG4SYSTEMS => CodecId::MPEG4SYSTEMS,
+            ffi::AVCodecID::AV_CODEC_ID_FFMETADATA => CodecId::FFMETADATA,
+            ffi::AVCodecID::AV_CODEC_ID_WRAPPED_AVFRAME => CodecId::WRAPPED_AVFRAME,
+            ffi::AVCodecID::AV_CODEC_ID_PSD => CodecId::PSD,
+            ffi::AVCodecID::AV_CODEC_ID_PIXLET => CodecId::PIXLET,
+            ffi::AVCodecID::AV_CODEC_ID_SPEEDHQ => CodecId::SPEEDHQ,
+            ffi::AVCodecID::AV_CODEC_ID_FMVC => CodecId::FMVC,
+            ffi::AVCodecID::AV_CODEC_ID_CLEARVIDEO => CodecId::CLEARVIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_SCPR => CodecId::SCPR,
+            ffi::AVCodecID::AV_CODEC_ID_XPM => CodecId::XPM,
+            ffi::AVCodecID::AV_CODEC_ID_AV1 => CodecId::AV1,
+            ffi::AVCodecID::AV_CODEC_ID_PCM_F16LE => CodecId::PCM_F16LE,
+            ffi::AVCodecID::AV_CODEC_ID_PCM_F24LE => CodecId::PCM_F24LE,
+            ffi::AVCodecID::AV_CODEC_ID_ATRAC3AL => CodecId::ATRAC3AL,
+            ffi::AVCodecID::AV_CODEC_ID_ATRAC3PAL => CodecId::ATRAC3PAL,
+            ffi::AVCodecID::AV_CODEC_ID_BITPACKED => CodecId::BITPACKED,
+            ffi::AVCodecID::AV_CODEC_ID_MSCC => CodecId::MSCC,
+            ffi::AVCodecID::AV_CODEC_ID_SRGC => CodecId::SRGC,
+            ffi::AVCodecID::AV_CODEC_ID_SVG => CodecId::SVG,
+            ffi::AVCodecID::AV_CODEC_ID_GDV => CodecId::GDV,
+            ffi::AVCodecID::AV_CODEC_ID_FITS => CodecId::FITS,
+            ffi::AVCodecID::AV_CODEC_ID_GREMLIN_DPCM => CodecId::GREMLIN_DPCM,
+            ffi::AVCodecID::AV_CODEC_ID_DOLBY_E => CodecId::DOLBY_E,
+            ffi::AVCodecID::AV_CODEC_ID_APTX => CodecId::APTX,
+            ffi::AVCodecID::AV_CODEC_ID_APTX_HD => CodecId::APTX_HD,
+            ffi::AVCodecID::AV_CODEC_ID_SBC => CodecId::SBC,
+            ffi::AVCodecID::AV_CODEC_ID_AVS2 => CodecId::AVS2,
+            ffi::AVCodecID::AV_CODEC_ID_IMM4 => CodecId::IMM4,
+            ffi::AVCodecID::AV_CODEC_ID_PROSUMER => CodecId::PROSUMER,
+            ffi::AVCodecID::AV_CODEC_ID_MWSC => CodecId::MWSC,
+            ffi::AVCodecID::AV_CODEC_ID_WCMV => CodecId::WCMV,
+            ffi::AVCodecID::AV_CODEC_ID_RASC => CodecId::RASC,
+            ffi::AVCodecID::AV_CODEC_ID_PCM_VIDC => CodecId::PCM_VIDC,
+            ffi::AVCodecID::AV_CODEC_ID_ATRAC9 => CodecId::ATRAC9,
+            ffi::AVCodecID::AV_CODEC_ID_TTML => CodecId::TTML,
+            ffi::AVCodecID::AV_CODEC_ID_HYMT => CodecId::HYMT,
+            ffi::AVCodecID::AV_CODEC_ID_ARBC => CodecId::ARBC,
+            ffi::AVCodecID::AV_CODEC_ID_AGM => CodecId::AGM,
+            ffi::AVCodecID::AV_CODEC_ID_LSCR => CodecId::LSCR,
+            ffi::AVCodecID::AV_CODEC_ID_VP4 => CodecId::VP4,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_AGM => CodecId::ADPCM_AGM,
+            ffi::AVCodecID::AV_CODEC_ID_HCOM => CodecId::HCOM,
+            ffi::AVCodecID::AV_CODEC_ID_ARIB_CAPTION => CodecId::ARIB_CAPTION,
+            ffi::AVCodecID::AV_CODEC_ID_IMM5 => CodecId::IMM5,
+            ffi::AVCodecID::AV_CODEC_ID_MVDV => CodecId::MVDV,
+            ffi::AVCodecID::AV_CODEC_ID_MVHA => CodecId::MVHA,
+            ffi::AVCodecID::AV_CODEC_ID_CDTOONS => CodecId::CDTOONS,
+            ffi::AVCodecID::AV_CODEC_ID_MV30 => CodecId::MV30,
+            ffi::AVCodecID::AV_CODEC_ID_NOTCHLC => CodecId::NOTCHLC,
+            ffi::AVCodecID::AV_CODEC_ID_PFM => CodecId::PFM,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_ARGO => CodecId::ADPCM_ARGO,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_SSI => CodecId::ADPCM_IMA_SSI,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_ZORK => CodecId::ADPCM_ZORK,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_APM => CodecId::ADPCM_IMA_APM,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_ALP => CodecId::ADPCM_IMA_ALP,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_MTF => CodecId::ADPCM_IMA_MTF,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_CUNNING => CodecId::ADPCM_IMA_CUNNING,
+            ffi::AVCodecID::AV_CODEC_ID_DERF_DPCM => CodecId::DERF_DPCM,
+            ffi::AVCodecID::AV_CODEC_ID_ACELP_KELVIN => CodecId::ACELP_KELVIN,
+            ffi::AVCodecID::AV_CODEC_ID_MPEGH_3D_AUDIO => CodecId::MPEGH_3D_AUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_SIREN => CodecId::SIREN,
+            ffi::AVCodecID::AV_CODEC_ID_HCA => CodecId::HCA,
+            ffi::AVCodecID::AV_CODEC_ID_EPG => CodecId::EPG,
+            ffi::AVCodecID::AV_CODEC_ID_PGX => CodecId::PGX,
+            ffi::AVCodecID::AV_CODEC_ID_AVS3 => CodecId::AVS3,
+            ffi::AVCodecID::AV_CODEC_ID_MSP2 => CodecId::MSP2,
+            ffi::AVCodecID::AV_CODEC_ID_VVC => CodecId::VVC,
+            ffi::AVCodecID::AV_CODEC_ID_MOBICLIP => CodecId::MOBICLIP,
+            ffi::AVCodecID::AV_CODEC_ID_PHOTOCD => CodecId::PHOTOCD,
+            ffi::AVCodecID::AV_CODEC_ID_IPU => CodecId::IPU,
+            ffi::AVCodecID::AV_CODEC_ID_ARGO => CodecId::ARGO,
+            ffi::AVCodecID::AV_CODEC_ID_CRI => CodecId::CRI,
+            ffi::AVCodecID::AV_CODEC_ID_SIMBIOSIS_IMX => CodecId::SIMBIOSIS_IMX,
+            ffi::AVCodecID::AV_CODEC_ID_SGA_VIDEO => CodecId::SGA_VIDEO,
+            ffi::AVCodecID::AV_CODEC_ID_PCM_SGA => CodecId::PCM_SGA,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_MOFLEX => CodecId::ADPCM_IMA_MOFLEX,
+            ffi::AVCodecID::AV_CODEC_ID_FASTAUDIO => CodecId::FASTAUDIO,
+            ffi::AVCodecID::AV_CODEC_ID_GEM => CodecId::GEM,
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_ACORN => CodecId::ADPCM_IMA_ACORN,
+            ffi::AVCodecID::AV_CODEC_ID_MSNSIREN => CodecId::MSNSIREN,
+            ffi::AVCodecID::AV_CODEC_ID_VBN => CodecId::VBN,
+            ffi::AVCodecID::AV_CODEC_ID_JPEGXL => CodecId::JPEGXL,
+            ffi::AVCodecID::AV_CODEC_ID_QOI => CodecId::QOI,
+            ffi::AVCodecID::AV_CODEC_ID_PHM => CodecId::PHM,
+            ffi::AVCodecID::AV_CODEC_ID_DFPWM => CodecId::DFPWM,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_RADIANCE_HDR => CodecId::RADIANCE_HDR,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_WBMP => CodecId::WBMP,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_MEDIA100 => CodecId::MEDIA100,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_VQC => CodecId::VQC,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_ADPCM_XMD => CodecId::ADPCM_XMD,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_WADY_DPCM => CodecId::WADY_DPCM,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_CBD2_DPCM => CodecId::CBD2_DPCM,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_BONK => CodecId::BONK,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_MISC4 => CodecId::MISC4,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_APAC => CodecId::APAC,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_FTR => CodecId::FTR,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_WAVARC => CodecId::WAVARC,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_RKA => CodecId::RKA,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_VNULL => CodecId::VNULL,
+            #[cfg(feature = "ffmpeg_6_0")]
+            ffi::AVCodecID::AV_CODEC_ID_ANULL => CodecId::ANULL,
+            #[cfg(feature = "ffmpeg_6_1")]
+            ffi::AVCodecID::AV_CODEC_ID_PDV => CodecId::PDV,
+            #[cfg(feature = "ffmpeg_6_1")]
+            ffi::AVCodecID::AV_CODEC_ID_EVC => CodecId::EVC,
+            #[cfg(feature = "ffmpeg_6_1")]
+            ffi::AVCodecID::AV_CODEC_ID_RTV1 => CodecId::RTV1,
+            #[cfg(feature = "ffmpeg_6_1")]
+            ffi::AVCodecID::AV_CODEC_ID_VMIX => CodecId::VMIX,
+            #[cfg(feature = "ffmpeg_6_1")]
+            ffi::AVCodecID::AV_CODEC_ID_AC4 => CodecId::AC4,
+            #[cfg(feature = "ffmpeg_6_1")]
+            ffi::AVCodecID::AV_CODEC_ID_SMPTE_2038 => CodecId::SMPTE_2038,
+            #[cfg(feature = "ffmpeg_6_1")]
+            ffi::AVCodecID::AV_CODEC_ID_OSQ => CodecId::OSQ,
+
+            #[allow(unreachable_patterns)]
+            other => CodecId::Other(UnknownCodecId(other)),
         }
     }
 }
@@ -104,6 +1177,223 @@ impl From<CodecId> for AVCodecID {
     fn from(value: CodecId) -> Self {
         match value {
             CodecId::NONE => ffi::AVCodecID::AV_CODEC_ID_NONE,
+
+            // 视频编解码器
+            CodecId::MPEG1VIDEO => ffi::AVCodecID::AV_CODEC_ID_MPEG1VIDEO,
+            CodecId::MPEG2VIDEO => ffi::AVCodecID::AV_CODEC_ID_MPEG2VIDEO,
+            CodecId::H261 => ffi::AVCodecID::AV_CODEC_ID_H261,
+            CodecId::H263 => ffi::AVCodecID::AV_CODEC_ID_H263,
+            CodecId::RV10 => ffi::AVCodecID::AV_CODEC_ID_RV10,
+            CodecId::RV20 => ffi::AVCodecID::AV_CODEC_ID_RV20,
+            CodecId::MJPEG => ffi::AVCodecID::AV_CODEC_ID_MJPEG,
+            CodecId::MJPEGB => ffi::AVCodecID::AV_CODEC_ID_MJPEGB,
+            CodecId::LJPEG => ffi::AVCodecID::AV_CODEC_ID_LJPEG,
+            CodecId::SP5X => ffi::AVCodecID::AV_CODEC_ID_SP5X,
+            CodecId::JPEGLS => ffi::AVCodecID::AV_CODEC_ID_JPEGLS,
+            CodecId::MPEG4 => ffi::AVCodecID::AV_CODEC_ID_MPEG4,
+            CodecId::RAWVIDEO => ffi::AVCodecID::AV_CODEC_ID_RAWVIDEO,
+            CodecId::MSMPEG4V1 => ffi::AVCodecID::AV_CODEC_ID_MSMPEG4V1,
+            CodecId::MSMPEG4V2 => ffi::AVCodecID::AV_CODEC_ID_MSMPEG4V2,
+            CodecId::MSMPEG4V3 => ffi::AVCodecID::AV_CODEC_ID_MSMPEG4V3,
+            CodecId::WMV1 => ffi::AVCodecID::AV_CODEC_ID_WMV1,
+            CodecId::WMV2 => ffi::AVCodecID::AV_CODEC_ID_WMV2,
+            CodecId::H263P => ffi::AVCodecID::AV_CODEC_ID_H263P,
+            CodecId::H263I => ffi::AVCodecID::AV_CODEC_ID_H263I,
+            CodecId::FLV1 => ffi::AVCodecID::AV_CODEC_ID_FLV1,
+            CodecId::SVQ1 => ffi::AVCodecID::AV_CODEC_ID_SVQ1,
+            CodecId::SVQ3 => ffi::AVCodecID::AV_CODEC_ID_SVQ3,
+            CodecId::DVVIDEO => ffi::AVCodecID::AV_CODEC_ID_DVVIDEO,
+            CodecId::HUFFYUV => ffi::AVCodecID::AV_CODEC_ID_HUFFYUV,
+            CodecId::CYUV => ffi::AVCodecID::AV_CODEC_ID_CYUV,
+            CodecId::H264 => ffi::AVCodecID::AV_CODEC_ID_H264,
+            CodecId::INDEO3 => ffi::AVCodecID::AV_CODEC_ID_INDEO3,
+            CodecId::VP3 => ffi::AVCodecID::AV_CODEC_ID_VP3,
+            CodecId::THEORA => ffi::AVCodecID::AV_CODEC_ID_THEORA,
+            CodecId::ASV1 => ffi::AVCodecID::AV_CODEC_ID_ASV1,
+            CodecId::ASV2 => ffi::AVCodecID::AV_CODEC_ID_ASV2,
+            CodecId::FFV1 => ffi::AVCodecID::AV_CODEC_ID_FFV1,
+            CodecId::XM4 => ffi::AVCodecID::AV_CODEC_ID_4XM,
+            CodecId::VCR1 => ffi::AVCodecID::AV_CODEC_ID_VCR1,
+            CodecId::CLJR => ffi::AVCodecID::AV_CODEC_ID_CLJR,
+            CodecId::MDEC => ffi::AVCodecID::AV_CODEC_ID_MDEC,
+            CodecId::ROQ => ffi::AVCodecID::AV_CODEC_ID_ROQ,
+            CodecId::INTERPLAY_VIDEO => ffi::AVCodecID::AV_CODEC_ID_INTERPLAY_VIDEO,
+            CodecId::XAN_WC3 => ffi::AVCodecID::AV_CODEC_ID_XAN_WC3,
+            CodecId::XAN_WC4 => ffi::AVCodecID::AV_CODEC_ID_XAN_WC4,
+            CodecId::RPZA => ffi::AVCodecID::AV_CODEC_ID_RPZA,
+            CodecId::CINEPAK => ffi::AVCodecID::AV_CODEC_ID_CINEPAK,
+            CodecId::WS_VQA => ffi::AVCodecID::AV_CODEC_ID_WS_VQA,
+            CodecId::MSRLE => ffi::AVCodecID::AV_CODEC_ID_MSRLE,
+            CodecId::MSVIDEO1 => ffi::AVCodecID::AV_CODEC_ID_MSVIDEO1,
+            CodecId::IDCIN => ffi::AVCodecID::AV_CODEC_ID_IDCIN,
+            CodecId::BPS8 => ffi::AVCodecID::AV_CODEC_ID_8BPS,
+            CodecId::SMC => ffi::AVCodecID::AV_CODEC_ID_SMC,
+            CodecId::FLIC => ffi::AVCodecID::AV_CODEC_ID_FLIC,
+            CodecId::TRUEMOTION1 => ffi::AVCodecID::AV_CODEC_ID_TRUEMOTION1,
+            CodecId::VMDVIDEO => ffi::AVCodecID::AV_CODEC_ID_VMDVIDEO,
+            CodecId::MSZH => ffi::AVCodecID::AV_CODEC_ID_MSZH,
+            CodecId::ZLIB => ffi::AVCodecID::AV_CODEC_ID_ZLIB,
+            CodecId::QTRLE => ffi::AVCodecID::AV_CODEC_ID_QTRLE,
+            CodecId::TSCC => ffi::AVCodecID::AV_CODEC_ID_TSCC,
+            CodecId::ULTI => ffi::AVCodecID::AV_CODEC_ID_ULTI,
+            CodecId::QDRAW => ffi::AVCodecID::AV_CODEC_ID_QDRAW,
+            CodecId::VIXL => ffi::AVCodecID::AV_CODEC_ID_VIXL,
+            CodecId::QPEG => ffi::AVCodecID::AV_CODEC_ID_QPEG,
+            CodecId::PNG => ffi::AVCodecID::AV_CODEC_ID_PNG,
+            CodecId::PPM => ffi::AVCodecID::AV_CODEC_ID_PPM,
+            CodecId::PBM => ffi::AVCodecID::AV_CODEC_ID_PBM,
+            CodecId::PGM => ffi::AVCodecID::AV_CODEC_ID_PGM,
+            CodecId::PGMYUV => ffi::AVCodecID::AV_CODEC_ID_PGMYUV,
+            CodecId::PAM => ffi::AVCodecID::AV_CODEC_ID_PAM,
+            CodecId::FFVHUFF => ffi::AVCodecID::AV_CODEC_ID_FFVHUFF,
+            CodecId::RV30 => ffi::AVCodecID::AV_CODEC_ID_RV30,
+            CodecId::RV40 => ffi::AVCodecID::AV_CODEC_ID_RV40,
+            CodecId::VC1 => ffi::AVCodecID::AV_CODEC_ID_VC1,
+            CodecId::WMV3 => ffi::AVCodecID::AV_CODEC_ID_WMV3,
+            CodecId::LOCO => ffi::AVCodecID::AV_CODEC_ID_LOCO,
+            CodecId::WNV1 => ffi::AVCodecID::AV_CODEC_ID_WNV1,
+            CodecId::AASC => ffi::AVCodecID::AV_CODEC_ID_AASC,
+            CodecId::INDEO2 => ffi::AVCodecID::AV_CODEC_ID_INDEO2,
+            CodecId::FRAPS => ffi::AVCodecID::AV_CODEC_ID_FRAPS,
+            CodecId::TRUEMOTION2 => ffi::AVCodecID::AV_CODEC_ID_TRUEMOTION2,
+            CodecId::BMP => ffi::AVCodecID::AV_CODEC_ID_BMP,
+            CodecId::CSCD => ffi::AVCodecID::AV_CODEC_ID_CSCD,
+            CodecId::MMVIDEO => ffi::AVCodecID::AV_CODEC_ID_MMVIDEO,
+            CodecId::ZMBV => ffi::AVCodecID::AV_CODEC_ID_ZMBV,
+            CodecId::AVS => ffi::AVCodecID::AV_CODEC_ID_AVS,
+            CodecId::SMACKVIDEO => ffi::AVCodecID::AV_CODEC_ID_SMACKVIDEO,
+            CodecId::NUV => ffi::AVCodecID::AV_CODEC_ID_NUV,
+            CodecId::KMVC => ffi::AVCodecID::AV_CODEC_ID_KMVC,
+            CodecId::FLASHSV => ffi::AVCodecID::AV_CODEC_ID_FLASHSV,
+            CodecId::CAVS => ffi::AVCodecID::AV_CODEC_ID_CAVS,
+            CodecId::JPEG2000 => ffi::AVCodecID::AV_CODEC_ID_JPEG2000,
+            CodecId::VMNC => ffi::AVCodecID::AV_CODEC_ID_VMNC,
+            CodecId::VP5 => ffi::AVCodecID::AV_CODEC_ID_VP5,
+            CodecId::VP6 => ffi::AVCodecID::AV_CODEC_ID_VP6,
+            CodecId::VP6F => ffi::AVCodecID::AV_CODEC_ID_VP6F,
+            CodecId::TARGA => ffi::AVCodecID::AV_CODEC_ID_TARGA,
+            CodecId::DSICINVIDEO => ffi::AVCodecID::AV_CODEC_ID_DSICINVIDEO,
+            CodecId::TIERTEXSEQVIDEO => ffi::AVCodecID::AV_CODEC_ID_TIERTEXSEQVIDEO,
+            CodecId::TIFF => ffi::AVCodecID::AV_CODEC_ID_TIFF,
+            CodecId::GIF => ffi::AVCodecID::AV_CODEC_ID_GIF,
+            CodecId::DXA => ffi::AVCodecID::AV_CODEC_ID_DXA,
+            CodecId::DNXHD => ffi::AVCodecID::AV_CODEC_ID_DNXHD,
+            CodecId::THP => ffi::AVCodecID::AV_CODEC_ID_THP,
+            CodecId::SGI => ffi::AVCodecID::AV_CODEC_ID_SGI,
+            CodecId::C93 => ffi::AVCodecID::AV_CODEC_ID_C93,
+            CodecId::BETHSOFTVID => ffi::AVCodecID::AV_CODEC_ID_BETHSOFTVID,
+            CodecId::PTX => ffi::AVCodecID::AV_CODEC_ID_PTX,
+            CodecId::TXD => ffi::AVCodecID::AV_CODEC_ID_TXD,
+            CodecId::VP6A => ffi::AVCodecID::AV_CODEC_ID_VP6A,
+            CodecId::AMV => ffi::AVCodecID::AV_CODEC_ID_AMV,
+            CodecId::VB => ffi::AVCodecID::AV_CODEC_ID_VB,
+            CodecId::PCX => ffi::AVCodecID::AV_CODEC_ID_PCX,
+            CodecId::SUNRAST => ffi::AVCodecID::AV_CODEC_ID_SUNRAST,
+            CodecId::INDEO4 => ffi::AVCodecID::AV_CODEC_ID_INDEO4,
+            CodecId::INDEO5 => ffi::AVCodecID::AV_CODEC_ID_INDEO5,
+            CodecId::MIMIC => ffi::AVCodecID::AV_CODEC_ID_MIMIC,
+            CodecId::RL2 => ffi::AVCodecID::AV_CODEC_ID_RL2,
+            CodecId::ESCAPE124 => ffi::AVCodecID::AV_CODEC_ID_ESCAPE124,
+            CodecId::DIRAC => ffi::AVCodecID::AV_CODEC_ID_DIRAC,
+            CodecId::BFI => ffi::AVCodecID::AV_CODEC_ID_BFI,
+            CodecId::CMV => ffi::AVCodecID::AV_CODEC_ID_CMV,
+            CodecId::MOTIONPIXELS => ffi::AVCodecID::AV_CODEC_ID_MOTIONPIXELS,
+            CodecId::TGV => ffi::AVCodecID::AV_CODEC_ID_TGV,
+            CodecId::TGQ => ffi::AVCodecID::AV_CODEC_ID_TGQ,
+            CodecId::TQI => ffi::AVCodecID::AV_CODEC_ID_TQI,
+            CodecId::AURA => ffi::AVCodecID::AV_CODEC_ID_AURA,
+            CodecId::AURA2 => ffi::AVCodecID::AV_CODEC_ID_AURA2,
+            CodecId::V210X => ffi::AVCodecID::AV_CODEC_ID_V210X,
+            CodecId::TMV => ffi::AVCodecID::AV_CODEC_ID_TMV,
+            CodecId::V210 => ffi::AVCodecID::AV_CODEC_ID_V210,
+            CodecId::DPX => ffi::AVCodecID::AV_CODEC_ID_DPX,
+            CodecId::MAD => ffi::AVCodecID::AV_CODEC_ID_MAD,
+            CodecId::FRWU => ffi::AVCodecID::AV_CODEC_ID_FRWU,
+            CodecId::FLASHSV2 => ffi::AVCodecID::AV_CODEC_ID_FLASHSV2,
+            CodecId::CDGRAPHICS => ffi::AVCodecID::AV_CODEC_ID_CDGRAPHICS,
+            CodecId::R210 => ffi::AVCodecID::AV_CODEC_ID_R210,
+            CodecId::ANM => ffi::AVCodecID::AV_CODEC_ID_ANM,
+            CodecId::BINKVIDEO => ffi::AVCodecID::AV_CODEC_ID_BINKVIDEO,
+            CodecId::IFF_ILBM => ffi::AVCodecID::AV_CODEC_ID_IFF_ILBM,
+            CodecId::KGV1 => ffi::AVCodecID::AV_CODEC_ID_KGV1,
+            CodecId::YOP => ffi::AVCodecID::AV_CODEC_ID_YOP,
+            CodecId::VP8 => ffi::AVCodecID::AV_CODEC_ID_VP8,
+            CodecId::PICTOR => ffi::AVCodecID::AV_CODEC_ID_PICTOR,
+            CodecId::ANSI => ffi::AVCodecID::AV_CODEC_ID_ANSI,
+            CodecId::A64_MULTI => ffi::AVCodecID::AV_CODEC_ID_A64_MULTI,
+            CodecId::A64_MULTI5 => ffi::AVCodecID::AV_CODEC_ID_A64_MULTI5,
+            CodecId::R10K => ffi::AVCodecID::AV_CODEC_ID_R10K,
+            CodecId::MXPEG => ffi::AVCodecID::AV_CODEC_ID_MXPEG,
+            CodecId::LAGARITH => ffi::AVCodecID::AV_CODEC_ID_LAGARITH,
+            CodecId::PRORES => ffi::AVCodecID::AV_CODEC_ID_PRORES,
+            CodecId::JV => ffi::AVCodecID::AV_CODEC_ID_JV,
+            CodecId::DFA => ffi::AVCodecID::AV_CODEC_ID_DFA,
+            CodecId::WMV3IMAGE => ffi::AVCodecID::AV_CODEC_ID_WMV3IMAGE,
+            CodecId::VC1IMAGE => ffi::AVCodecID::AV_CODEC_ID_VC1IMAGE,
+            CodecId::UTVIDEO => ffi::AVCodecID::AV_CODEC_ID_UTVIDEO,
+            CodecId::BMV_VIDEO => ffi::AVCodecID::AV_CODEC_ID_BMV_VIDEO,
+            CodecId::VBLE => ffi::AVCodecID::AV_CODEC_ID_VBLE,
+            CodecId::DXTORY => ffi::AVCodecID::AV_CODEC_ID_DXTORY,
+            CodecId::V410 => ffi::AVCodecID::AV_CODEC_ID_V410,
+            CodecId::XWD => ffi::AVCodecID::AV_CODEC_ID_XWD,
+            CodecId::CDXL => ffi::AVCodecID::AV_CODEC_ID_CDXL,
+            CodecId::XBM => ffi::AVCodecID::AV_CODEC_ID_XBM,
+            CodecId::ZEROCODEC => ffi::AVCodecID::AV_CODEC_ID_ZEROCODEC,
+            CodecId::MSS1 => ffi::AVCodecID::AV_CODEC_ID_MSS1,
+            CodecId::MSA1 => ffi::AVCodecID::AV_CODEC_ID_MSA1,
+            CodecId::TSCC2 => ffi::AVCodecID::AV_CODEC_ID_TSCC2,
+            CodecId::MTS2 => ffi::AVCodecID::AV_CODEC_ID_MTS2,
+            CodecId::CLLC => ffi::AVCodecID::AV_CODEC_ID_CLLC,
+            CodecId::MSS2 => ffi::AVCodecID::AV_CODEC_ID_MSS2,
+            CodecId::VP9 => ffi::AVCodecID::AV_CODEC_ID_VP9,
+            CodecId::AIC => ffi::AVCodecID::AV_CODEC_ID_AIC,
+            CodecId::ESCAPE130 => ffi::AVCodecID::AV_CODEC_ID_ESCAPE130,
+            CodecId::G2M => ffi::AVCodecID::AV_CODEC_ID_G2M,
+            CodecId::WEBP => ffi::AVCodecID::AV_CODEC_ID_WEBP,
+            CodecId::HNM4_VIDEO => ffi::AVCodecID::AV_CODEC_ID_HNM4_VIDEO,
+            CodecId::HEVC => ffi::AVCodecID::AV_CODEC_ID_HEVC,
+            CodecId::FIC => ffi::AVCodecID::AV_CODEC_ID_FIC,
+            CodecId::ALIAS_PIX => ffi::AVCodecID::AV_CODEC_ID_ALIAS_PIX,
+            CodecId::BRENDER_PIX => ffi::AVCodecID::AV_CODEC_ID_BRENDER_PIX,
+            CodecId::PAF_VIDEO => ffi::AVCodecID::AV_CODEC_ID_PAF_VIDEO,
+            CodecId::EXR => ffi::AVCodecID::AV_CODEC_ID_EXR,
+            CodecId::VP7 => ffi::AVCodecID::AV_CODEC_ID_VP7,
+            CodecId::SANM => ffi::AVCodecID::AV_CODEC_ID_SANM,
+            CodecId::SGIRLE => ffi::AVCodecID::AV_CODEC_ID_SGIRLE,
+            CodecId::MVC1 => ffi::AVCodecID::AV_CODEC_ID_MVC1,
+            CodecId::MVC2 => ffi::AVCodecID::AV_CODEC_ID_MVC2,
+            CodecId::HQX => ffi::AVCodecID::AV_CODEC_ID_HQX,
+            CodecId::TDSC => ffi::AVCodecID::AV_CODEC_ID_TDSC,
+            CodecId::HQ_HQA => ffi::AVCodecID::AV_CODEC_ID_HQ_HQA,
+            CodecId::HAP => ffi::AVCodecID::AV_CODEC_ID_HAP,
+            CodecId::DDS => ffi::AVCodecID::AV_CODEC_ID_DDS,
+            CodecId::DXV => ffi::AVCodecID::AV_CODEC_ID_DXV,
+            CodecId::SCREENPRESSO => ffi::AVCodecID::AV_CODEC_ID_SCREENPRESSO,
+            CodecId::RSCC => ffi::AVCodecID::AV_CODEC_ID_RSCC,
+            CodecId::Y41P => ffi::AVCodecID::AV_CODEC_ID_Y41P,
+            CodecId::AVRP => ffi::AVCodecID::AV_CODEC_ID_AVRP,
+            CodecId::V012 => ffi::AVCodecID::AV_CODEC_ID_012V,
+            CodecId::AVUI => ffi::AVCodecID::AV_CODEC_ID_AVUI,
+            CodecId::AYUV => ffi::AVCodecID::AV_CODEC_ID_AYUV,
+            CodecId::TARGA_Y216 => ffi::AVCodecID::AV_CODEC_ID_TARGA_Y216,
+            CodecId::V308 => ffi::AVCodecID::AV_CODEC_ID_V308,
+            CodecId::V408 => ffi::AVCodecID::AV_CODEC_ID_V408,
+            CodecId::YUV4 => ffi::AVCodecID::AV_CODEC_ID_YUV4,
+            CodecId::AVRN => ffi::AVCodecID::AV_CODEC_ID_AVRN,
+            CodecId::CPIA => ffi::AVCodecID::AV_CODEC_ID_CPIA,
+            CodecId::XFACE => ffi::AVCodecID::AV_CODEC_ID_XFACE,
+            CodecId::SNOW => ffi::AVCodecID::AV_CODEC_ID_SNOW,
+            CodecId::SMVJPEG => ffi::AVCodecID::AV_CODEC_ID_SMVJPEG,
+            CodecId::APNG => ffi::AVCodecID::AV_CODEC_ID_APNG,
+            CodecId::DAALA => ffi::AVCodecID::AV_CODEC_ID_DAALA,
+            CodecId::CFHD => ffi::AVCodecID::AV_CODEC_ID_CFHD,
+            CodecId::TRUEMOTION2RT => ffi::AVCodecID::AV_CODEC_ID_TRUEMOTION2RT,
+            CodecId::M101 => ffi::AVCodecID::AV_CODEC_ID_M101,
+            CodecId::MAGICYUV => ffi::AVCodecID::AV_CODEC_ID_MAGICYUV,
+            CodecId::SHEERVIDEO => ffi::AVCodecID::AV_CODEC_ID_SHEERVIDEO,
+            CodecId::YLC => ffi::AVCodecID::AV_CODEC_ID_YLC,
+
+            // pcm 编解码器
             CodecId::PCM_S16LE => ffi::AVCodecID::AV_CODEC_ID_PCM_S16LE,
             CodecId::PCM_S16BE => ffi::AVCodecID::AV_CODEC_ID_PCM_S16BE,
             CodecId::PCM_U16LE => ffi::AVCodecID::AV_CODEC_ID_PCM_U16LE,
@@ -136,12 +1426,324 @@ impl From<CodecId> for AVCodecID {
             CodecId::PCM_S32LE_PLANAR => ffi::AVCodecID::AV_CODEC_ID_PCM_S32LE_PLANAR,
             CodecId::PCM_S16BE_PLANAR => ffi::AVCodecID::AV_CODEC_ID_PCM_S16BE_PLANAR,
             CodecId::PCM_S64LE => ffi::AVCodecID::AV_CODEC_ID_PCM_S64LE,
-            CodecId::PCM_S64BE => ffi::AVCodecID::AV_CODEC_ID_PCM_S64BE
+            CodecId::PCM_S64BE => ffi::AVCodecID::AV_CODEC_ID_PCM_S64BE,
+
+            // adpcm 编解码器
+            CodecId::ADPCM_IMA_QT => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_QT,
+            CodecId::ADPCM_IMA_WAV => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_WAV,
+            CodecId::ADPCM_IMA_DK3 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_DK3,
+            CodecId::ADPCM_IMA_DK4 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_DK4,
+            CodecId::ADPCM_IMA_WS => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_WS,
+            CodecId::ADPCM_IMA_SMJPEG => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_SMJPEG,
+            CodecId::ADPCM_MS => ffi::AVCodecID::AV_CODEC_ID_ADPCM_MS,
+            CodecId::ADPCM_4XM => ffi::AVCodecID::AV_CODEC_ID_ADPCM_4XM,
+            CodecId::ADPCM_XA => ffi::AVCodecID::AV_CODEC_ID_ADPCM_XA,
+            CodecId::ADPCM_ADX => ffi::AVCodecID::AV_CODEC_ID_ADPCM_ADX,
+            CodecId::ADPCM_EA => ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA,
+            CodecId::ADPCM_G726 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_G726,
+            CodecId::ADPCM_CT => ffi::AVCodecID::AV_CODEC_ID_ADPCM_CT,
+            CodecId::ADPCM_SWF => ffi::AVCodecID::AV_CODEC_ID_ADPCM_SWF,
+            CodecId::ADPCM_YAMAHA => ffi::AVCodecID::AV_CODEC_ID_ADPCM_YAMAHA,
+            CodecId::ADPCM_SBPRO_4 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_SBPRO_4,
+            CodecId::ADPCM_SBPRO_3 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_SBPRO_3,
+            CodecId::ADPCM_SBPRO_2 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_SBPRO_2,
+            CodecId::ADPCM_THP => ffi::AVCodecID::AV_CODEC_ID_ADPCM_THP,
+            CodecId::ADPCM_IMA_AMV => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_AMV,
+            CodecId::ADPCM_EA_R1 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_R1,
+            CodecId::ADPCM_EA_R3 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_R3,
+            CodecId::ADPCM_EA_R2 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_R2,
+            CodecId::ADPCM_IMA_EA_SEAD => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_EA_SEAD,
+            CodecId::ADPCM_IMA_EA_EACS => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_EA_EACS,
+            CodecId::ADPCM_EA_XAS => ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_XAS,
+            CodecId::ADPCM_EA_MAXIS_XA => ffi::AVCodecID::AV_CODEC_ID_ADPCM_EA_MAXIS_XA,
+            CodecId::ADPCM_IMA_ISS => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_ISS,
+            CodecId::ADPCM_G722 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_G722,
+            CodecId::ADPCM_IMA_APC => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_APC,
+            CodecId::ADPCM_VIMA => ffi::AVCodecID::AV_CODEC_ID_ADPCM_VIMA,
+            CodecId::ADPCM_AFC => ffi::AVCodecID::AV_CODEC_ID_ADPCM_AFC,
+            CodecId::ADPCM_IMA_OKI => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_OKI,
+            CodecId::ADPCM_DTK => ffi::AVCodecID::AV_CODEC_ID_ADPCM_DTK,
+            CodecId::ADPCM_IMA_RAD => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_RAD,
+            CodecId::ADPCM_G726LE => ffi::AVCodecID::AV_CODEC_ID_ADPCM_G726LE,
+            CodecId::ADPCM_THP_LE => ffi::AVCodecID::AV_CODEC_ID_ADPCM_THP_LE,
+            CodecId::ADPCM_PSX => ffi::AVCodecID::AV_CODEC_ID_ADPCM_PSX,
+            CodecId::ADPCM_AICA => ffi::AVCodecID::AV_CODEC_ID_ADPCM_AICA,
+            CodecId::ADPCM_IMA_DAT4 => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_DAT4,
+            CodecId::ADPCM_MTAF => ffi::AVCodecID::AV_CODEC_ID_ADPCM_MTAF,
+
+            // amr 编解码器
+            CodecId::AMR_NB => ffi::AVCodecID::AV_CODEC_ID_AMR_NB,
+            CodecId::AMR_WB => ffi::AVCodecID::AV_CODEC_ID_AMR_WB,
+
+            // RealAudio 编解码器
+            CodecId::RA_144 => ffi::AVCodecID::AV_CODEC_ID_RA_144,
+            CodecId::RA_288 => ffi::AVCodecID::AV_CODEC_ID_RA_288,
+
+            // dpcm 编解码器
+            CodecId::ROQ_DPCM => ffi::AVCodecID::AV_CODEC_ID_ROQ_DPCM,
+            CodecId::INTERPLAY_DPCM => ffi::AVCodecID::AV_CODEC_ID_INTERPLAY_DPCM,
+            CodecId::XAN_DPCM => ffi::AVCodecID::AV_CODEC_ID_XAN_DPCM,
+            CodecId::SOL_DPCM => ffi::AVCodecID::AV_CODEC_ID_SOL_DPCM,
+            CodecId::SDX2_DPCM => ffi::AVCodecID::AV_CODEC_ID_SDX2_DPCM,
+
+            // 音频编解码器
+            CodecId::MP2 => ffi::AVCodecID::AV_CODEC_ID_MP2,
+            CodecId::MP3 => ffi::AVCodecID::AV_CODEC_ID_MP3,
+            CodecId::AAC => ffi::AVCodecID::AV_CODEC_ID_AAC,
+            CodecId::AC3 => ffi::AVCodecID::AV_CODEC_ID_AC3,
+            CodecId::DTS => ffi::AVCodecID::AV_CODEC_ID_DTS,
+            CodecId::VORBIS => ffi::AVCodecID::AV_CODEC_ID_VORBIS,
+            CodecId::DVAUDIO => ffi::AVCodecID::AV_CODEC_ID_DVAUDIO,
+            CodecId::WMAV1 => ffi::AVCodecID::AV_CODEC_ID_WMAV1,
+            CodecId::WMAV2 => ffi::AVCodecID::AV_CODEC_ID_WMAV2,
+            CodecId::MACE3 => ffi::AVCodecID::AV_CODEC_ID_MACE3,
+            CodecId::MACE6 => ffi::AVCodecID::AV_CODEC_ID_MACE6,
+            CodecId::VMDAUDIO => ffi::AVCodecID::AV_CODEC_ID_VMDAUDIO,
+            CodecId::FLAC => ffi::AVCodecID::AV_CODEC_ID_FLAC,
+            CodecId::MP3ADU => ffi::AVCodecID::AV_CODEC_ID_MP3ADU,
+            CodecId::MP3ON4 => ffi::AVCodecID::AV_CODEC_ID_MP3ON4,
+            CodecId::SHORTEN => ffi::AVCodecID::AV_CODEC_ID_SHORTEN,
+            CodecId::ALAC => ffi::AVCodecID::AV_CODEC_ID_ALAC,
+            CodecId::WESTWOOD_SND1 => ffi::AVCodecID::AV_CODEC_ID_WESTWOOD_SND1,
+            CodecId::GSM => ffi::AVCodecID::AV_CODEC_ID_GSM,
+            CodecId::QDM2 => ffi::AVCodecID::AV_CODEC_ID_QDM2,
+            CodecId::COOK => ffi::AVCodecID::AV_CODEC_ID_COOK,
+            CodecId::TRUESPEECH => ffi::AVCodecID::AV_CODEC_ID_TRUESPEECH,
+            CodecId::TTA => ffi::AVCodecID::AV_CODEC_ID_TTA,
+            CodecId::SMACKAUDIO => ffi::AVCodecID::AV_CODEC_ID_SMACKAUDIO,
+            CodecId::QCELP => ffi::AVCodecID::AV_CODEC_ID_QCELP,
+            CodecId::WAVPACK => ffi::AVCodecID::AV_CODEC_ID_WAVPACK,
+            CodecId::DSICINAUDIO => ffi::AVCodecID::AV_CODEC_ID_DSICINAUDIO,
+            CodecId::IMC => ffi::AVCodecID::AV_CODEC_ID_IMC,
+            CodecId::MUSEPACK7 => ffi::AVCodecID::AV_CODEC_ID_MUSEPACK7,
+            CodecId::MLP => ffi::AVCodecID::AV_CODEC_ID_MLP,
+            CodecId::GSM_MS => ffi::AVCodecID::AV_CODEC_ID_GSM_MS,
+            CodecId::ATRAC3 => ffi::AVCodecID::AV_CODEC_ID_ATRAC3,
+            CodecId::APE => ffi::AVCodecID::AV_CODEC_ID_APE,
+            CodecId::NELLYMOSER => ffi::AVCodecID::AV_CODEC_ID_NELLYMOSER,
+            CodecId::MUSEPACK8 => ffi::AVCodecID::AV_CODEC_ID_MUSEPACK8,
+            CodecId::SPEEX => ffi::AVCodecID::AV_CODEC_ID_SPEEX,
+            CodecId::WMAVOICE => ffi::AVCodecID::AV_CODEC_ID_WMAVOICE,
+            CodecId::WMAPRO => ffi::AVCodecID::AV_CODEC_ID_WMAPRO,
+            CodecId::WMALOSSLESS => ffi::AVCodecID::AV_CODEC_ID_WMALOSSLESS,
+            CodecId::ATRAC3P => ffi::AVCodecID::AV_CODEC_ID_ATRAC3P,
+            CodecId::EAC3 => ffi::AVCodecID::AV_CODEC_ID_EAC3,
+            CodecId::SIPR => ffi::AVCodecID::AV_CODEC_ID_SIPR,
+            CodecId::MP1 => ffi::AVCodecID::AV_CODEC_ID_MP1,
+            CodecId::TWINVQ => ffi::AVCodecID::AV_CODEC_ID_TWINVQ,
+            CodecId::TRUEHD => ffi::AVCodecID::AV_CODEC_ID_TRUEHD,
+            CodecId::MP4ALS => ffi::AVCodecID::AV_CODEC_ID_MP4ALS,
+            CodecId::ATRAC1 => ffi::AVCodecID::AV_CODEC_ID_ATRAC1,
+            CodecId::BINKAUDIO_RDFT => ffi::AVCodecID::AV_CODEC_ID_BINKAUDIO_RDFT,
+            CodecId::BINKAUDIO_DCT => ffi::AVCodecID::AV_CODEC_ID_BINKAUDIO_DCT,
+            CodecId::AAC_LATM => ffi::AVCodecID::AV_CODEC_ID_AAC_LATM,
+            CodecId::QDMC => ffi::AVCodecID::AV_CODEC_ID_QDMC,
+            CodecId::CELT => ffi::AVCodecID::AV_CODEC_ID_CELT,
+            CodecId::G723_1 => ffi::AVCodecID::AV_CODEC_ID_G723_1,
+            CodecId::G729 => ffi::AVCodecID::AV_CODEC_ID_G729,
+            CodecId::SVX_EXP8 => ffi::AVCodecID::AV_CODEC_ID_8SVX_EXP,
+            CodecId::SVX_FIB8 => ffi::AVCodecID::AV_CODEC_ID_8SVX_FIB,
+            CodecId::BMV_AUDIO => ffi::AVCodecID::AV_CODEC_ID_BMV_AUDIO,
+            CodecId::RALF => ffi::AVCodecID::AV_CODEC_ID_RALF,
+            CodecId::IAC => ffi::AVCodecID::AV_CODEC_ID_IAC,
+            CodecId::ILBC => ffi::AVCodecID::AV_CODEC_ID_ILBC,
+            CodecId::OPUS => ffi::AVCodecID::AV_CODEC_ID_OPUS,
+            CodecId::COMFORT_NOISE => ffi::AVCodecID::AV_CODEC_ID_COMFORT_NOISE,
+            CodecId::TAK => ffi::AVCodecID::AV_CODEC_ID_TAK,
+            CodecId::METASOUND => ffi::AVCodecID::AV_CODEC_ID_METASOUND,
+            CodecId::PAF_AUDIO => ffi::AVCodecID::AV_CODEC_ID_PAF_AUDIO,
+            CodecId::ON2AVC => ffi::AVCodecID::AV_CODEC_ID_ON2AVC,
+            CodecId::DSS_SP => ffi::AVCodecID::AV_CODEC_ID_DSS_SP,
+            CodecId::CODEC2 => ffi::AVCodecID::AV_CODEC_ID_CODEC2,
+            CodecId::FFWAVESYNTH => ffi::AVCodecID::AV_CODEC_ID_FFWAVESYNTH,
+            CodecId::SONIC => ffi::AVCodecID::AV_CODEC_ID_SONIC,
+            CodecId::SONIC_LS => ffi::AVCodecID::AV_CODEC_ID_SONIC_LS,
+            CodecId::EVRC => ffi::AVCodecID::AV_CODEC_ID_EVRC,
+            CodecId::SMV => ffi::AVCodecID::AV_CODEC_ID_SMV,
+            CodecId::DSD_LSBF => ffi::AVCodecID::AV_CODEC_ID_DSD_LSBF,
+            CodecId::DSD_MSBF => ffi::AVCodecID::AV_CODEC_ID_DSD_MSBF,
+            CodecId::DSD_LSBF_PLANAR => ffi::AVCodecID::AV_CODEC_ID_DSD_LSBF_PLANAR,
+            CodecId::DSD_MSBF_PLANAR => ffi::AVCodecID::AV_CODEC_ID_DSD_MSBF_PLANAR,
+            CodecId::_4GV => ffi::AVCodecID::AV_CODEC_ID_4GV,
+            CodecId::INTERPLAY_ACM => ffi::AVCodecID::AV_CODEC_ID_INTERPLAY_ACM,
+            CodecId::XMA1 => ffi::AVCodecID::AV_CODEC_ID_XMA1,
+            CodecId::XMA2 => ffi::AVCodecID::AV_CODEC_ID_XMA2,
+            CodecId::DST => ffi::AVCodecID::AV_CODEC_ID_DST,
+
+            // 字幕编解码器
+            CodecId::DVD_SUBTITLE => ffi::AVCodecID::AV_CODEC_ID_DVD_SUBTITLE,
+            CodecId::DVB_SUBTITLE => ffi::AVCodecID::AV_CODEC_ID_DVB_SUBTITLE,
+            CodecId::TEXT => ffi::AVCodecID::AV_CODEC_ID_TEXT,
+            CodecId::XSUB => ffi::AVCodecID::AV_CODEC_ID_XSUB,
+            CodecId::SSA => ffi::AVCodecID::AV_CODEC_ID_SSA,
+            CodecId::MOV_TEXT => ffi::AVCodecID::AV_CODEC_ID_MOV_TEXT,
+            CodecId::HDMV_PGS_SUBTITLE => ffi::AVCodecID::AV_CODEC_ID_HDMV_PGS_SUBTITLE,
+            CodecId::DVB_TELETEXT => ffi::AVCodecID::AV_CODEC_ID_DVB_TELETEXT,
+            CodecId::SRT => ffi::AVCodecID::AV_CODEC_ID_SRT,
+            CodecId::MICRODVD => ffi::AVCodecID::AV_CODEC_ID_MICRODVD,
+            CodecId::EIA_608 => ffi::AVCodecID::AV_CODEC_ID_EIA_608,
+            CodecId::JACOSUB => ffi::AVCodecID::AV_CODEC_ID_JACOSUB,
+            CodecId::SAMI => ffi::AVCodecID::AV_CODEC_ID_SAMI,
+            CodecId::REALTEXT => ffi::AVCodecID::AV_CODEC_ID_REALTEXT,
+            CodecId::STL => ffi::AVCodecID::AV_CODEC_ID_STL,
+            CodecId::SUBVIEWER1 => ffi::AVCodecID::AV_CODEC_ID_SUBVIEWER1,
+            CodecId::SUBVIEWER => ffi::AVCodecID::AV_CODEC_ID_SUBVIEWER,
+            CodecId::SUBRIP => ffi::AVCodecID::AV_CODEC_ID_SUBRIP,
+            CodecId::WEBVTT => ffi::AVCodecID::AV_CODEC_ID_WEBVTT,
+            CodecId::MPL2 => ffi::AVCodecID::AV_CODEC_ID_MPL2,
+            CodecId::VPLAYER => ffi::AVCodecID::AV_CODEC_ID_VPLAYER,
+            CodecId::PJS => ffi::AVCodecID::AV_CODEC_ID_PJS,
+            CodecId::ASS => ffi::AVCodecID::AV_CODEC_ID_ASS,
+            CodecId::HDMV_TEXT_SUBTITLE => ffi::AVCodecID::AV_CODEC_ID_HDMV_TEXT_SUBTITLE,
+
+            // 其他编解码器（一般用于附件）以及伪编解码器
+            CodecId::TTF => ffi::AVCodecID::AV_CODEC_ID_TTF,
+            CodecId::SCTE_35 => ffi::AVCodecID::AV_CODEC_ID_SCTE_35,
+            CodecId::BINTEXT => ffi::AVCodecID::AV_CODEC_ID_BINTEXT,
+            CodecId::XBIN => ffi::AVCodecID::AV_CODEC_ID_XBIN,
+            CodecId::IDF => ffi::AVCodecID::AV_CODEC_ID_IDF,
+            CodecId::OTF => ffi::AVCodecID::AV_CODEC_ID_OTF,
+            CodecId::SMPTE_KLV => ffi::AVCodecID::AV_CODEC_ID_SMPTE_KLV,
+            CodecId::DVD_NAV => ffi::AVCodecID::AV_CODEC_ID_DVD_NAV,
+            CodecId::TIMED_ID3 => ffi::AVCodecID::AV_CODEC_ID_TIMED_ID3,
+            CodecId::BIN_DATA => ffi::AVCodecID::AV_CODEC_ID_BIN_DATA,
+            CodecId::PROBE => ffi::AVCodecID::AV_CODEC_ID_PROBE,
+            CodecId::MPEG2TS => ffi::AVCodecID::AV_CODEC_ID_MPEG2TS,
+            CodecId::MPEG4SYSTEMS => ffi::AVCodecID::AV_CODEC_ID_MPEG4SYSTEMS,
+            CodecId::FFMETADATA => ffi::AVCodecID::AV_CODEC_ID_FFMETADATA,
+            CodecId::WRAPPED_AVFRAME => ffi::AVCodecID::AV_CODEC_ID_WRAPPED_AVFRAME,
+            CodecId::PSD => ffi::AVCodecID::AV_CODEC_ID_PSD,
+            CodecId::PIXLET => ffi::AVCodecID::AV_CODEC_ID_PIXLET,
+            CodecId::SPEEDHQ => ffi::AVCodecID::AV_CODEC_ID_SPEEDHQ,
+            CodecId::FMVC => ffi::AVCodecID::AV_CODEC_ID_FMVC,
+            CodecId::CLEARVIDEO => ffi::AVCodecID::AV_CODEC_ID_CLEARVIDEO,
+            CodecId::SCPR => ffi::AVCodecID::AV_CODEC_ID_SCPR,
+            CodecId::XPM => ffi::AVCodecID::AV_CODEC_ID_XPM,
+            CodecId::AV1 => ffi::AVCodecID::AV_CODEC_ID_AV1,
+            CodecId::PCM_F16LE => ffi::AVCodecID::AV_CODEC_ID_PCM_F16LE,
+            CodecId::PCM_F24LE => ffi::AVCodecID::AV_CODEC_ID_PCM_F24LE,
+            CodecId::ATRAC3AL => ffi::AVCodecID::AV_CODEC_ID_ATRAC3AL,
+            CodecId::ATRAC3PAL => ffi::AVCodecID::AV_CODEC_ID_ATRAC3PAL,
+            CodecId::BITPACKED => ffi::AVCodecID::AV_CODEC_ID_BITPACKED,
+            CodecId::MSCC => ffi::AVCodecID::AV_CODEC_ID_MSCC,
+            CodecId::SRGC => ffi::AVCodecID::AV_CODEC_ID_SRGC,
+            CodecId::SVG => ffi::AVCodecID::AV_CODEC_ID_SVG,
+            CodecId::GDV => ffi::AVCodecID::AV_CODEC_ID_GDV,
+            CodecId::FITS => ffi::AVCodecID::AV_CODEC_ID_FITS,
+            CodecId::GREMLIN_DPCM => ffi::AVCodecID::AV_CODEC_ID_GREMLIN_DPCM,
+            CodecId::DOLBY_E => ffi::AVCodecID::AV_CODEC_ID_DOLBY_E,
+            CodecId::APTX => ffi::AVCodecID::AV_CODEC_ID_APTX,
+            CodecId::APTX_HD => ffi::AVCodecID::AV_CODEC_ID_APTX_HD,
+            CodecId::SBC => ffi::AVCodecID::AV_CODEC_ID_SBC,
+            CodecId::AVS2 => ffi::AVCodecID::AV_CODEC_ID_AVS2,
+            CodecId::IMM4 => ffi::AVCodecID::AV_CODEC_ID_IMM4,
+            CodecId::PROSUMER => ffi::AVCodecID::AV_CODEC_ID_PROSUMER,
+            CodecId::MWSC => ffi::AVCodecID::AV_CODEC_ID_MWSC,
+            CodecId::WCMV => ffi::AVCodecID::AV_CODEC_ID_WCMV,
+            CodecId::RASC => ffi::AVCodecID::AV_CODEC_ID_RASC,
+            CodecId::PCM_VIDC => ffi::AVCodecID::AV_CODEC_ID_PCM_VIDC,
+            CodecId::ATRAC9 => ffi::AVCodecID::AV_CODEC_ID_ATRAC9,
+            CodecId::TTML => ffi::AVCodecID::AV_CODEC_ID_TTML,
+            CodecId::HYMT => ffi::AVCodecID::AV_CODEC_ID_HYMT,
+            CodecId::ARBC => ffi::AVCodecID::AV_CODEC_ID_ARBC,
+            CodecId::AGM => ffi::AVCodecID::AV_CODEC_ID_AGM,
+            CodecId::LSCR => ffi::AVCodecID::AV_CODEC_ID_LSCR,
+            CodecId::VP4 => ffi::AVCodecID::AV_CODEC_ID_VP4,
+            CodecId::ADPCM_AGM => ffi::AVCodecID::AV_CODEC_ID_ADPCM_AGM,
+            CodecId::HCOM => ffi::AVCodecID::AV_CODEC_ID_HCOM,
+            CodecId::ARIB_CAPTION => ffi::AVCodecID::AV_CODEC_ID_ARIB_CAPTION,
+            CodecId::IMM5 => ffi::AVCodecID::AV_CODEC_ID_IMM5,
+            CodecId::MVDV => ffi::AVCodecID::AV_CODEC_ID_MVDV,
+            CodecId::MVHA => ffi::AVCodecID::AV_CODEC_ID_MVHA,
+            CodecId::CDTOONS => ffi::AVCodecID::AV_CODEC_ID_CDTOONS,
+            CodecId::MV30 => ffi::AVCodecID::AV_CODEC_ID_MV30,
+            CodecId::NOTCHLC => ffi::AVCodecID::AV_CODEC_ID_NOTCHLC,
+            CodecId::PFM => ffi::AVCodecID::AV_CODEC_ID_PFM,
+            CodecId::ADPCM_ARGO => ffi::AVCodecID::AV_CODEC_ID_ADPCM_ARGO,
+            CodecId::ADPCM_IMA_SSI => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_SSI,
+            CodecId::ADPCM_ZORK => ffi::AVCodecID::AV_CODEC_ID_ADPCM_ZORK,
+            CodecId::ADPCM_IMA_APM => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_APM,
+            CodecId::ADPCM_IMA_ALP => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_ALP,
+            CodecId::ADPCM_IMA_MTF => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_MTF,
+            CodecId::ADPCM_IMA_CUNNING => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_CUNNING,
+            CodecId::DERF_DPCM => ffi::AVCodecID::AV_CODEC_ID_DERF_DPCM,
+            CodecId::ACELP_KELVIN => ffi::AVCodecID::AV_CODEC_ID_ACELP_KELVIN,
+            CodecId::MPEGH_3D_AUDIO => ffi::AVCodecID::AV_CODEC_ID_MPEGH_3D_AUDIO,
+            CodecId::SIREN => ffi::AVCodecID::AV_CODEC_ID_SIREN,
+            CodecId::HCA => ffi::AVCodecID::AV_CODEC_ID_HCA,
+            CodecId::EPG => ffi::AVCodecID::AV_CODEC_ID_EPG,
+            CodecId::PGX => ffi::AVCodecID::AV_CODEC_ID_PGX,
+            CodecId::AVS3 => ffi::AVCodecID::AV_CODEC_ID_AVS3,
+            CodecId::MSP2 => ffi::AVCodecID::AV_CODEC_ID_MSP2,
+            CodecId::VVC => ffi::AVCodecID::AV_CODEC_ID_VVC,
+            CodecId::MOBICLIP => ffi::AVCodecID::AV_CODEC_ID_MOBICLIP,
+            CodecId::PHOTOCD => ffi::AVCodecID::AV_CODEC_ID_PHOTOCD,
+            CodecId::IPU => ffi::AVCodecID::AV_CODEC_ID_IPU,
+            CodecId::ARGO => ffi::AVCodecID::AV_CODEC_ID_ARGO,
+            CodecId::CRI => ffi::AVCodecID::AV_CODEC_ID_CRI,
+            CodecId::SIMBIOSIS_IMX => ffi::AVCodecID::AV_CODEC_ID_SIMBIOSIS_IMX,
+            CodecId::SGA_VIDEO => ffi::AVCodecID::AV_CODEC_ID_SGA_VIDEO,
+            CodecId::PCM_SGA => ffi::AVCodecID::AV_CODEC_ID_PCM_SGA,
+            CodecId::ADPCM_IMA_MOFLEX => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_MOFLEX,
+            CodecId::FASTAUDIO => ffi::AVCodecID::AV_CODEC_ID_FASTAUDIO,
+            CodecId::GEM => ffi::AVCodecID::AV_CODEC_ID_GEM,
+            CodecId::ADPCM_IMA_ACORN => ffi::AVCodecID::AV_CODEC_ID_ADPCM_IMA_ACORN,
+            CodecId::MSNSIREN => ffi::AVCodecID::AV_CODEC_ID_MSNSIREN,
+            CodecId::VBN => ffi::AVCodecID::AV_CODEC_ID_VBN,
+            CodecId::JPEGXL => ffi::AVCodecID::AV_CODEC_ID_JPEGXL,
+            CodecId::QOI => ffi::AVCodecID::AV_CODEC_ID_QOI,
+            CodecId::PHM => ffi::AVCodecID::AV_CODEC_ID_PHM,
+            CodecId::DFPWM => ffi::AVCodecID::AV_CODEC_ID_DFPWM,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::RADIANCE_HDR => ffi::AVCodecID::AV_CODEC_ID_RADIANCE_HDR,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::WBMP => ffi::AVCodecID::AV_CODEC_ID_WBMP,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::MEDIA100 => ffi::AVCodecID::AV_CODEC_ID_MEDIA100,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::VQC => ffi::AVCodecID::AV_CODEC_ID_VQC,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::ADPCM_XMD => ffi::AVCodecID::AV_CODEC_ID_ADPCM_XMD,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::WADY_DPCM => ffi::AVCodecID::AV_CODEC_ID_WADY_DPCM,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::CBD2_DPCM => ffi::AVCodecID::AV_CODEC_ID_CBD2_DPCM,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::BONK => ffi::AVCodecID::AV_CODEC_ID_BONK,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::MISC4 => ffi::AVCodecID::AV_CODEC_ID_MISC4,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::APAC => ffi::AVCodecID::AV_CODEC_ID_APAC,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::FTR => ffi::AVCodecID::AV_CODEC_ID_FTR,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::WAVARC => ffi::AVCodecID::AV_CODEC_ID_WAVARC,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::RKA => ffi::AVCodecID::AV_CODEC_ID_RKA,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::VNULL => ffi::AVCodecID::AV_CODEC_ID_VNULL,
+            #[cfg(feature = "ffmpeg_6_0")]
+            CodecId::ANULL => ffi::AVCodecID::AV_CODEC_ID_ANULL,
+            #[cfg(feature = "ffmpeg_6_1")]
+            CodecId::PDV => ffi::AVCodecID::AV_CODEC_ID_PDV,
+            #[cfg(feature = "ffmpeg_6_1")]
+            CodecId::EVC => ffi::AVCodecID::AV_CODEC_ID_EVC,
+            #[cfg(feature = "ffmpeg_6_1")]
+            CodecId::RTV1 => ffi::AVCodecID::AV_CODEC_ID_RTV1,
+            #[cfg(feature = "ffmpeg_6_1")]
+            CodecId::VMIX => ffi::AVCodecID::AV_CODEC_ID_VMIX,
+            #[cfg(feature = "ffmpeg_6_1")]
+            CodecId::AC4 => ffi::AVCodecID::AV_CODEC_ID_AC4,
+            #[cfg(feature = "ffmpeg_6_1")]
+            CodecId::SMPTE_2038 => ffi::AVCodecID::AV_CODEC_ID_SMPTE_2038,
+            #[cfg(feature = "ffmpeg_6_1")]
+            CodecId::OSQ => ffi::AVCodecID::AV_CODEC_ID_OSQ,
+
+            CodecId::Other(UnknownCodecId(id)) => id,
         }
     }
 }
 
-
 /// 根据codec_id获取编码名称
 pub fn avcodec_get_name(id: CodecId) -> Option<String> {
     let name = unsafe { ffi::avcodec_get_name(id.into()) };
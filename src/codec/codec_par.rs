@@ -1,18 +1,27 @@
 use std::any::Any;
-use std::rc::Rc;
+use std::sync::Arc;
+use std::{mem, ptr, slice};
+
+use libc::{c_int, c_void};
 
 use crate::codec::codec_id::CodecId;
+use crate::codec::context::Context;
 use crate::ffi;
+use crate::util::channel_layout::ChannelLayout;
+use crate::util::error::{Error, Result};
+use crate::util::media;
+use crate::util::samplefmt::SampleFormat;
 
 pub struct Parameters {
     ptr: *mut ffi::AVCodecParameters,
-    owner: Option<Rc<dyn Any>>,
+    owner: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 unsafe impl Send for Parameters {}
 
 impl Parameters {
-    pub unsafe fn wrap(ptr: *mut ffi::AVCodecParameters, owner: Option<Rc<dyn Any>>) -> Self {
+    /// owner 不为空时参数由 owner 持有，owner 需保证 ptr 不会被其他线程同时修改
+    pub unsafe fn wrap(ptr: *mut ffi::AVCodecParameters, owner: Option<Arc<dyn Any + Send + Sync>>) -> Self {
         Parameters { ptr, owner }
     }
 
@@ -32,10 +41,235 @@ impl Parameters {
         }
     }
 
+    /// 从编解码器上下文拷贝参数，用于设置输出流
+    pub fn from_context(context: &Context) -> Result<Self> {
+        let mut parameters = Parameters::new();
+        unsafe {
+            match ffi::avcodec_parameters_from_context(parameters.as_mut_ptr(), context.as_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(parameters),
+            }
+        }
+    }
+
     #[inline]
     pub fn codec_id(&self) ->CodecId {
         unsafe { (*self.as_ptr()).codec_id.into() }
     }
+
+    pub fn set_codec_id(&mut self, value: CodecId) {
+        unsafe {
+            (*self.as_mut_ptr()).codec_id = value.into();
+        }
+    }
+
+    /// 媒体类型
+    pub fn medium(&self) -> media::Type {
+        unsafe { media::Type::from((*self.as_ptr()).codec_type) }
+    }
+
+    pub fn set_medium(&mut self, value: media::Type) {
+        unsafe {
+            (*self.as_mut_ptr()).codec_type = value.into();
+        }
+    }
+
+    /// 封装格式中的编码标识，如 wav 中的 format tag
+    pub fn codec_tag(&self) -> u32 {
+        unsafe { (*self.as_ptr()).codec_tag }
+    }
+
+    pub fn set_codec_tag(&mut self, value: u32) {
+        unsafe {
+            (*self.as_mut_ptr()).codec_tag = value;
+        }
+    }
+
+    /// 平均码率，单位 bit/s
+    pub fn bit_rate(&self) -> i64 {
+        unsafe { (*self.as_ptr()).bit_rate }
+    }
+
+    pub fn set_bit_rate(&mut self, value: i64) {
+        unsafe {
+            (*self.as_mut_ptr()).bit_rate = value;
+        }
+    }
+
+    /// 编码后每个采样的位数
+    pub fn bits_per_coded_sample(&self) -> i32 {
+        unsafe { (*self.as_ptr()).bits_per_coded_sample }
+    }
+
+    pub fn set_bits_per_coded_sample(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).bits_per_coded_sample = value;
+        }
+    }
+
+    /// 原始采样的有效位数，如 24 位的 pcm 存放在 s32 中
+    pub fn bits_per_raw_sample(&self) -> i32 {
+        unsafe { (*self.as_ptr()).bits_per_raw_sample }
+    }
+
+    pub fn set_bits_per_raw_sample(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).bits_per_raw_sample = value;
+        }
+    }
+
+    pub fn profile(&self) -> i32 {
+        unsafe { (*self.as_ptr()).profile }
+    }
+
+    pub fn set_profile(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).profile = value;
+        }
+    }
+
+    pub fn level(&self) -> i32 {
+        unsafe { (*self.as_ptr()).level }
+    }
+
+    pub fn set_level(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).level = value;
+        }
+    }
+
+    pub fn rate(&self) -> i32 {
+        unsafe { (*self.as_ptr()).sample_rate }
+    }
+
+    pub fn set_rate(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).sample_rate = value;
+        }
+    }
+
+    pub fn channels(&self) -> i32 {
+        unsafe { (*self.as_ptr()).ch_layout.nb_channels }
+    }
+
+    pub fn channel_layout(&self) -> ChannelLayout {
+        unsafe { ChannelLayout::from_ptr(&(*self.as_ptr()).ch_layout) }
+    }
+
+    pub fn set_channel_layout(&mut self, value: ChannelLayout) {
+        unsafe {
+            ffi::av_channel_layout_copy(&mut (*self.as_mut_ptr()).ch_layout, value.as_ptr());
+        }
+    }
+
+    /// 音频的采样格式，视频参数中该字段为像素格式，此时返回 NONE
+    pub fn format(&self) -> SampleFormat {
+        unsafe {
+            let format = (*self.as_ptr()).format;
+            if self.medium() != media::Type::Audio || format < 0 || format >= ffi::AVSampleFormat::AV_SAMPLE_FMT_NB as c_int {
+                SampleFormat::NONE
+            } else {
+                SampleFormat::from(mem::transmute::<c_int, ffi::AVSampleFormat>(format))
+            }
+        }
+    }
+
+    pub fn set_format(&mut self, value: SampleFormat) {
+        unsafe {
+            (*self.as_mut_ptr()).format = mem::transmute::<ffi::AVSampleFormat, c_int>(value.into());
+        }
+    }
+
+    /// 每个音频帧的采样数，0 表示可变帧长
+    pub fn frame_size(&self) -> i32 {
+        unsafe { (*self.as_ptr()).frame_size }
+    }
+
+    pub fn set_frame_size(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).frame_size = value;
+        }
+    }
+
+    /// 每个音频包的字节数，pcm 等固定帧长的编码使用
+    pub fn block_align(&self) -> i32 {
+        unsafe { (*self.as_ptr()).block_align }
+    }
+
+    pub fn set_block_align(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).block_align = value;
+        }
+    }
+
+    /// 编码器在开头插入的填充采样数，解码后需要丢弃
+    pub fn initial_padding(&self) -> i32 {
+        unsafe { (*self.as_ptr()).initial_padding }
+    }
+
+    pub fn set_initial_padding(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).initial_padding = value;
+        }
+    }
+
+    /// 结尾的填充采样数
+    pub fn trailing_padding(&self) -> i32 {
+        unsafe { (*self.as_ptr()).trailing_padding }
+    }
+
+    pub fn set_trailing_padding(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).trailing_padding = value;
+        }
+    }
+
+    /// seek 之后需要丢弃的采样数
+    pub fn seek_preroll(&self) -> i32 {
+        unsafe { (*self.as_ptr()).seek_preroll }
+    }
+
+    pub fn set_seek_preroll(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).seek_preroll = value;
+        }
+    }
+
+    /// 编解码器的额外数据，如 aac 的 AudioSpecificConfig
+    pub fn extradata(&self) -> Option<&[u8]> {
+        unsafe {
+            let par = self.as_ptr();
+            if (*par).extradata.is_null() || (*par).extradata_size <= 0 {
+                None
+            } else {
+                Some(slice::from_raw_parts((*par).extradata, (*par).extradata_size as usize))
+            }
+        }
+    }
+
+    /// 设置额外数据，空数据表示清除
+    pub fn set_extradata(&mut self, data: &[u8]) -> Result<()> {
+        unsafe {
+            let par = self.as_mut_ptr();
+            ffi::av_freep(&mut (*par).extradata as *mut *mut u8 as *mut c_void);
+            (*par).extradata_size = 0;
+            if data.is_empty() {
+                return Ok(());
+            }
+
+            // 末尾需要补零的填充字节，供解码器越界读取
+            let size = data.len() + ffi::AV_INPUT_BUFFER_PADDING_SIZE as usize;
+            let extradata = ffi::av_mallocz(size) as *mut u8;
+            if extradata.is_null() {
+                return Err(Error::NoMemory);
+            }
+
+            ptr::copy_nonoverlapping(data.as_ptr(), extradata, data.len());
+            (*par).extradata = extradata;
+            (*par).extradata_size = data.len() as c_int;
+        }
+        Ok(())
+    }
 }
 
 impl Drop for Parameters {
@@ -1,5 +1,6 @@
 use std::ops::{Deref, DerefMut};
 use crate::codec::context::Context;
+use crate::ffi;
 use crate::util::channel_layout::ChannelLayout;
 use crate::util::samplefmt::SampleFormat;
 use super::Decoder;
@@ -12,7 +13,7 @@ impl Audio {
     }
 
     pub fn channels(&self) -> i32 {
-        self.channel_layout().nb_channels()
+        unsafe { (*self.as_ptr()).ch_layout.nb_channels }
     }
 
     pub fn format(&self) -> SampleFormat {
@@ -31,7 +32,7 @@ impl Audio {
 
     pub fn channel_layout(&self) -> ChannelLayout {
         unsafe {
-            ChannelLayout::wrap(&(*self.as_ptr()).ch_layout as *const _ as *mut _)
+            ChannelLayout::from_ptr(&(*self.as_ptr()).ch_layout)
         }
     }
 
@@ -42,7 +43,7 @@ impl Audio {
 
     pub fn set_channel_layout(&mut self, value: ChannelLayout) {
         unsafe {
-            (*self.as_mut_ptr()).ch_layout = *(value.as_ptr());
+            ffi::av_channel_layout_copy(&mut (*self.as_mut_ptr()).ch_layout, value.as_ptr());
         }
     }
 
@@ -5,11 +5,11 @@ pub use self::audio::Audio;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
 use super::Context;
-use anyhow::{anyhow, Result};
 use crate::codec::codec::Codec;
 use crate::ffi;
 use crate::format::packet::Packet;
 use crate::util::dict::Dictionary;
+use crate::util::error::{Error, Result};
 use crate::util::frame::Frame;
 
 pub struct Decoder {
@@ -25,7 +25,7 @@ impl Decoder {
                     self.open = true;
                     Ok(self)
                 }
-                e => Err(anyhow!("avcodec open failed: {}", e)),
+                e => Err(Error::from(e)),
             }
         }
     }
@@ -37,7 +37,7 @@ impl Decoder {
                     self.open = true;
                     Ok(self)
                 }
-                e => Err(anyhow!("avcodec open failed: {}", e)),
+                e => Err(Error::from(e)),
             }
         }
     }
@@ -52,20 +52,16 @@ impl Decoder {
                     self.open = true;
                     Ok(self)
                 }
-                e => Err(anyhow!("avcodec open failed: {}", e)),
+                e => Err(Error::from(e)),
             }
         }
     }
 
     pub fn audio(self) -> Result<Audio> {
         if let Some(codec) = super::find(self.id()) {
-            let opened = self.open_as(codec);
-            match opened {
-                Ok(audio) => Ok(Audio(audio)),
-                Err(e) => Err(anyhow!("avcodec open failed: {}", e)),
-            }
+            self.open_as(codec).map(Audio)
         } else {
-            return Err(anyhow!("audio decoder not found"));
+            Err(Error::DecoderNotFound)
         }
     }
 
@@ -73,11 +69,11 @@ impl Decoder {
         unsafe {
             if self.open {
                 match ffi::avcodec_send_packet(self.as_mut_ptr(), packet.as_ptr()) {
-                    e if e < 0 => Err(anyhow!("send packet failed: {}", e)),
+                    e if e < 0 => Err(Error::from(e)),
                     _ => Ok(()),
                 }
             } else {
-                Err(anyhow!("decoder not open"))
+                Err(Error::NotOpen)
             }
 
         }
@@ -88,11 +84,11 @@ impl Decoder {
         unsafe {
             if self.open {
                 match ffi::avcodec_send_packet(self.as_mut_ptr(), ptr::null()) {
-                    e if e < 0 => Err(anyhow!("send eof failed: {}", e)),
+                    e if e < 0 => Err(Error::from(e)),
                     _ => Ok(()),
                 }
             } else {
-                Err(anyhow!("decoder not open"))
+                Err(Error::NotOpen)
             }
 
         }
@@ -102,15 +98,22 @@ impl Decoder {
         unsafe {
             if self.open {
                 match ffi::avcodec_receive_frame(self.as_mut_ptr(), frame.as_mut_ptr()) {
-                    e if e < 0 => Err(anyhow!("receive frame failed: {}", e)),
+                    e if e < 0 => Err(Error::from(e)),
                     _ => Ok(()),
                 }
             } else {
-                Err(anyhow!("decoder not open"))
+                Err(Error::NotOpen)
             }
 
         }
     }
+
+    /// 清空解码器内部缓存，在输入 seek 之后调用
+    pub fn flush(&mut self) {
+        unsafe {
+            ffi::avcodec_flush_buffers(self.as_mut_ptr());
+        }
+    }
 }
 
 impl Deref for Decoder {
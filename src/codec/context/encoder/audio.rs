@@ -0,0 +1,99 @@
+use std::ops::{Deref, DerefMut};
+use crate::codec::codec::Codec;
+use crate::codec::context::Context;
+use crate::ffi;
+use crate::util::channel_layout::ChannelLayout;
+use crate::util::dict::Dictionary;
+use crate::util::error::Result;
+use crate::util::samplefmt::SampleFormat;
+use super::Encoder;
+
+pub struct Audio(pub Encoder);
+
+impl Audio {
+    pub fn open(self) -> Result<Audio> {
+        self.0.open().map(Audio)
+    }
+
+    pub fn open_as(self, codec: Codec) -> Result<Audio> {
+        self.0.open_as(codec).map(Audio)
+    }
+
+    /// 使用指定的编码器及私有参数打开，未被使用的参数会被丢弃
+    pub fn open_as_with(self, codec: Codec, opt: Dictionary) -> Result<Audio> {
+        self.0.open_as_with(codec, opt).map(Audio)
+    }
+
+    pub fn rate(&self) -> i32 {
+        unsafe { (*self.as_ptr()).sample_rate }
+    }
+
+    pub fn set_rate(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).sample_rate = value;
+        }
+    }
+
+    pub fn channels(&self) -> i32 {
+        unsafe { (*self.as_ptr()).ch_layout.nb_channels }
+    }
+
+    pub fn format(&self) -> SampleFormat {
+        unsafe { SampleFormat::from((*self.as_ptr()).sample_fmt) }
+    }
+
+    pub fn set_format(&mut self, value: SampleFormat) {
+        unsafe {
+            (*self.as_mut_ptr()).sample_fmt = value.into();
+        }
+    }
+
+    pub fn channel_layout(&self) -> ChannelLayout {
+        unsafe {
+            ChannelLayout::from_ptr(&(*self.as_ptr()).ch_layout)
+        }
+    }
+
+    pub fn set_channel_layout(&mut self, value: ChannelLayout) {
+        unsafe {
+            ffi::av_channel_layout_copy(&mut (*self.as_mut_ptr()).ch_layout, value.as_ptr());
+        }
+    }
+
+    /// 每个音频帧的采样数，编码器打开后由编码器填充，0 表示可变帧长
+    pub fn frame_size(&self) -> i32 {
+        unsafe { (*self.as_ptr()).frame_size }
+    }
+
+    pub fn set_frame_size(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).frame_size = value;
+        }
+    }
+}
+
+impl Deref for Audio {
+    type Target = Encoder;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Audio {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Context> for Audio {
+    fn as_ref(&self) -> &Context {
+        self
+    }
+}
+
+impl AsMut<Context> for Audio {
+    fn as_mut(&mut self) -> &mut Context {
+        self
+    }
+}
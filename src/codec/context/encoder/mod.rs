@@ -0,0 +1,161 @@
+pub mod audio;
+
+pub use self::audio::Audio;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use super::Context;
+use crate::codec::codec::Codec;
+use crate::ffi;
+use crate::format::packet::Packet;
+use crate::util::dict::Dictionary;
+use crate::util::error::{Error, Result};
+use crate::util::frame::Frame;
+use crate::util::media;
+use crate::util::rational::Rational;
+
+pub struct Encoder {
+    pub context: Context,
+    pub open: bool,
+}
+
+impl Encoder {
+    pub fn open(mut self) -> Result<Encoder> {
+        unsafe {
+            match ffi::avcodec_open2(self.as_mut_ptr(), ptr::null(), ptr::null_mut()) {
+                0 => {
+                    self.open = true;
+                    Ok(self)
+                }
+                e => Err(Error::from(e)),
+            }
+        }
+    }
+
+    pub fn open_as(mut self, codec: Codec) -> Result<Encoder> {
+        unsafe {
+            match ffi::avcodec_open2(self.as_mut_ptr(), codec.as_ptr(), ptr::null_mut()) {
+                0 => {
+                    self.open = true;
+                    Ok(self)
+                }
+                e => Err(Error::from(e)),
+            }
+        }
+    }
+
+    pub fn open_as_with(mut self, codec: Codec, opt: Dictionary) -> Result<Encoder> {
+        unsafe {
+            let mut dict = opt.into_raw();
+            let res = ffi::avcodec_open2(self.as_mut_ptr(), codec.as_ptr(), &mut dict);
+            Dictionary::from_raw(dict);
+            match res {
+                0 => {
+                    self.open = true;
+                    Ok(self)
+                }
+                e => Err(Error::from(e)),
+            }
+        }
+    }
+
+    /// 音频编码器，打开前需要先设置采样率、采样格式、声道布局等参数
+    pub fn audio(mut self) -> Result<Audio> {
+        match self.medium() {
+            media::Type::Unknown => {
+                unsafe {
+                    (*self.as_mut_ptr()).codec_type = media::Type::Audio.into();
+                }
+                Ok(Audio(self))
+            }
+            media::Type::Audio => Ok(Audio(self)),
+            _ => Err(Error::InvalidMediaType),
+        }
+    }
+
+    /// 码率
+    pub fn bit_rate(&self) -> i64 {
+        unsafe { (*self.as_ptr()).bit_rate }
+    }
+
+    pub fn set_bit_rate(&mut self, value: i64) {
+        unsafe {
+            (*self.as_mut_ptr()).bit_rate = value;
+        }
+    }
+
+    pub fn time_base(&self) -> Rational {
+        unsafe { Rational::from((*self.as_ptr()).time_base) }
+    }
+
+    pub fn set_time_base<R: Into<Rational>>(&mut self, value: R) {
+        unsafe {
+            (*self.as_mut_ptr()).time_base = value.into().into();
+        }
+    }
+
+    pub fn send_frame(&mut self, frame: &Frame) -> Result<()> {
+        unsafe {
+            if self.open {
+                match ffi::avcodec_send_frame(self.as_mut_ptr(), frame.as_ptr()) {
+                    e if e < 0 => Err(Error::from(e)),
+                    _ => Ok(()),
+                }
+            } else {
+                Err(Error::NotOpen)
+            }
+        }
+    }
+
+    /// 结束编码，进入排水模式
+    pub fn send_eof(&mut self) -> Result<()> {
+        unsafe {
+            if self.open {
+                match ffi::avcodec_send_frame(self.as_mut_ptr(), ptr::null()) {
+                    e if e < 0 => Err(Error::from(e)),
+                    _ => Ok(()),
+                }
+            } else {
+                Err(Error::NotOpen)
+            }
+        }
+    }
+
+    pub fn receive_packet(&mut self, packet: &mut Packet) -> Result<()> {
+        unsafe {
+            if self.open {
+                match ffi::avcodec_receive_packet(self.as_mut_ptr(), packet.as_mut_ptr()) {
+                    e if e < 0 => Err(Error::from(e)),
+                    _ => Ok(()),
+                }
+            } else {
+                Err(Error::NotOpen)
+            }
+        }
+    }
+}
+
+impl Deref for Encoder {
+    type Target = Context;
+
+    fn deref(&self) -> &Self::Target {
+        &self.context
+    }
+}
+
+impl DerefMut for Encoder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.context
+    }
+}
+
+impl AsRef<Context> for Encoder {
+    fn as_ref(&self) -> &Context {
+        self
+    }
+}
+
+impl AsMut<Context> for Encoder {
+    fn as_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
@@ -1,24 +1,30 @@
 pub mod decoder;
+pub mod encoder;
 
 use std::any::Any;
 use std::ptr;
-use std::rc::Rc;
+use std::sync::Arc;
+use libc::c_void;
 use crate::codec::codec_par::Parameters;
 use crate::ffi;
-use anyhow::{anyhow, Result};
+use crate::util::error::{Error, Result};
 use crate::codec::codec::Codec;
 use crate::codec::codec_id::CodecId;
 use crate::codec::context::decoder::Decoder;
+use crate::codec::context::encoder::Encoder;
+use crate::util::media;
+use crate::util::options::Options;
 
 pub struct Context {
     ptr: *mut ffi::AVCodecContext,
-    owner: Option<Rc<dyn Any>>,
+    owner: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 unsafe impl Send for Context {}
 
 impl Context {
-    pub unsafe fn wrap(ptr: *mut ffi::AVCodecContext, owner: Option<Rc<dyn Any>>) -> Self {
+    /// owner 不为空时上下文由 owner 持有，owner 需保证 ptr 不会被其他线程同时使用
+    pub unsafe fn wrap(ptr: *mut ffi::AVCodecContext, owner: Option<Arc<dyn Any + Send + Sync>>) -> Self {
         Context { ptr, owner }
     }
 
@@ -46,7 +52,7 @@ impl Context {
 
         unsafe {
             match ffi::avcodec_parameters_to_context(context.as_mut_ptr(), parameters.as_ptr()) {
-                e if e < 0 => Err(anyhow!("codec parameters copy to context failed: {}", e)),
+                e if e < 0 => Err(Error::from(e)),
                 _ => Ok(context),
             }
         }
@@ -66,6 +72,11 @@ impl Context {
         }
     }
 
+    /// 媒体类型
+    pub fn medium(&self) -> media::Type {
+        unsafe { media::Type::from((*self.as_ptr()).codec_type) }
+    }
+
     /// 解码器
     pub fn decoder(self) -> Decoder {
         Decoder {
@@ -73,6 +84,20 @@ impl Context {
             open: false,
         }
     }
+
+    /// 编码器
+    pub fn encoder(self) -> Encoder {
+        Encoder {
+            context: self,
+            open: false,
+        }
+    }
+}
+
+impl Options for Context {
+    unsafe fn as_options_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
 }
 
 impl Drop for Context {
@@ -95,4 +120,16 @@ pub fn find(id: CodecId) -> Option<Codec> {
             Some(Codec::wrap(ptr))
         }
     }
-}
\ No newline at end of file
+}
+
+/// 通过编解码器ID获取编码器对象
+pub fn find_encoder(id: CodecId) -> Option<Codec> {
+    unsafe {
+        let ptr = ffi::avcodec_find_encoder(id.into()) as *mut ffi::AVCodec;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Codec::wrap(ptr))
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::ffi::CStr;
+use std::str::from_utf8_unchecked;
+
+use crate::codec::codec_id::CodecId;
+use crate::codec::props::Props;
+use crate::ffi;
+use crate::util::media;
+use libc::c_uint;
+
+/// 编解码器描述，与具体实现无关，同一个 CodecId 的所有编解码器共享
+#[derive(Copy, Clone)]
+pub struct Descriptor {
+    ptr: *const ffi::AVCodecDescriptor,
+}
+
+unsafe impl Send for Descriptor {}
+unsafe impl Sync for Descriptor {}
+
+impl Descriptor {
+    pub unsafe fn wrap(ptr: *const ffi::AVCodecDescriptor) -> Self {
+        Descriptor { ptr }
+    }
+
+    pub unsafe fn as_ptr(&self) -> *const ffi::AVCodecDescriptor {
+        self.ptr
+    }
+}
+
+impl Descriptor {
+    /// 按编解码器 ID 查找描述
+    pub fn find(id: CodecId) -> Option<Descriptor> {
+        unsafe {
+            let ptr = ffi::avcodec_descriptor_get(id.into());
+            if ptr.is_null() {
+                None
+            } else {
+                Some(Descriptor::wrap(ptr))
+            }
+        }
+    }
+
+    pub fn id(&self) -> CodecId {
+        unsafe { CodecId::from((*self.as_ptr()).id) }
+    }
+
+    pub fn medium(&self) -> media::Type {
+        unsafe { media::Type::from((*self.as_ptr()).type_) }
+    }
+
+    pub fn name(&self) -> &'static str {
+        unsafe { from_utf8_unchecked(CStr::from_ptr((*self.as_ptr()).name).to_bytes()) }
+    }
+
+    pub fn long_name(&self) -> &'static str {
+        unsafe {
+            let long_name = (*self.as_ptr()).long_name;
+            if long_name.is_null() {
+                ""
+            } else {
+                from_utf8_unchecked(CStr::from_ptr(long_name).to_bytes())
+            }
+        }
+    }
+
+    /// 编码特性，如是否有损、是否只有帧内编码
+    pub fn props(&self) -> Props {
+        unsafe { Props::from_bits_truncate((*self.as_ptr()).props as c_uint) }
+    }
+
+    pub fn is_lossless(&self) -> bool {
+        self.props().contains(Props::LOSSLESS)
+    }
+
+    pub fn is_lossy(&self) -> bool {
+        self.props().contains(Props::LOSSY)
+    }
+
+    pub fn is_intra_only(&self) -> bool {
+        self.props().contains(Props::INTRA_ONLY)
+    }
+
+    /// 对应的 MIME 类型
+    pub fn mime_types(&self) -> Vec<&'static str> {
+        let mut types = Vec::new();
+        unsafe {
+            let mut ptr = (*self.as_ptr()).mime_types;
+            if ptr.is_null() {
+                return types;
+            }
+
+            while !(*ptr).is_null() {
+                types.push(from_utf8_unchecked(CStr::from_ptr(*ptr).to_bytes()));
+                ptr = ptr.add(1);
+            }
+        }
+        types
+    }
+}
@@ -7,4 +7,9 @@ pub use self::context::Context;
 
 pub mod capabilities;
 pub mod audio;
+pub mod descriptor;
+pub mod props;
+pub mod registry;
+pub use self::descriptor::Descriptor;
+pub use self::registry::{codecs, decoders, encoders, find_decoder_by_name, find_encoder_by_name};
 
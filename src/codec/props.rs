@@ -0,0 +1,14 @@
+use bitflags::bitflags;
+use crate::ffi::*;
+use libc::c_uint;
+
+bitflags! {
+    pub struct Props: c_uint {
+        const INTRA_ONLY = AV_CODEC_PROP_INTRA_ONLY;
+        const LOSSY      = AV_CODEC_PROP_LOSSY;
+        const LOSSLESS   = AV_CODEC_PROP_LOSSLESS;
+        const REORDER    = AV_CODEC_PROP_REORDER;
+        const BITMAP_SUB = AV_CODEC_PROP_BITMAP_SUB;
+        const TEXT_SUB   = AV_CODEC_PROP_TEXT_SUB;
+    }
+}
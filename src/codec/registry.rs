@@ -0,0 +1,74 @@
+use std::ffi::CString;
+use std::ptr;
+
+use libc::c_void;
+
+use crate::codec::codec::Codec;
+use crate::ffi;
+
+/// 已注册编解码器的迭代器
+pub struct CodecIter {
+    opaque: *mut c_void,
+    filter: fn(&Codec) -> bool,
+}
+
+impl Iterator for CodecIter {
+    type Item = Codec;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            loop {
+                let ptr = ffi::av_codec_iterate(&mut self.opaque);
+                if ptr.is_null() {
+                    return None;
+                }
+
+                let codec = Codec::wrap(ptr as *mut _);
+                if (self.filter)(&codec) {
+                    return Some(codec);
+                }
+            }
+        }
+    }
+}
+
+/// 所有已注册的编解码器
+pub fn codecs() -> CodecIter {
+    CodecIter { opaque: ptr::null_mut(), filter: |_| true }
+}
+
+/// 所有已注册的解码器
+pub fn decoders() -> CodecIter {
+    CodecIter { opaque: ptr::null_mut(), filter: Codec::is_decoder }
+}
+
+/// 所有已注册的编码器
+pub fn encoders() -> CodecIter {
+    CodecIter { opaque: ptr::null_mut(), filter: Codec::is_encoder }
+}
+
+/// 按名称查找解码器，如 "libopus"、"pcm_alaw"
+pub fn find_decoder_by_name(name: &str) -> Option<Codec> {
+    let name = CString::new(name).ok()?;
+    unsafe {
+        let ptr = ffi::avcodec_find_decoder_by_name(name.as_ptr()) as *mut ffi::AVCodec;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Codec::wrap(ptr))
+        }
+    }
+}
+
+/// 按名称查找编码器，如 "libfdk_aac"、"aac"
+pub fn find_encoder_by_name(name: &str) -> Option<Codec> {
+    let name = CString::new(name).ok()?;
+    unsafe {
+        let ptr = ffi::avcodec_find_encoder_by_name(name.as_ptr()) as *mut ffi::AVCodec;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Codec::wrap(ptr))
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::ptr;
+use libc::c_void;
+
+use crate::codec::context::decoder;
+use crate::ffi;
+use crate::util::channel_layout::ChannelLayout;
+use crate::util::error::{c_string, Error, Result};
+use crate::util::frame;
+use crate::util::options::Options;
+use crate::util::rational::Rational;
+use crate::util::samplefmt::SampleFormat;
+
+/// 输入端 abuffer 的名称，即滤镜描述中的 [in]
+const SOURCE_NAME: &str = "in";
+/// 输出端 abuffersink 的名称，即滤镜描述中的 [out]
+const SINK_NAME: &str = "out";
+
+/// 音频滤镜图：abuffer → 滤镜链 → abuffersink
+pub struct Graph {
+    ptr: *mut ffi::AVFilterGraph,
+    source: *mut ffi::AVFilterContext,
+    sink: *mut ffi::AVFilterContext,
+}
+
+impl Graph {
+    pub unsafe fn as_ptr(&self) -> *const ffi::AVFilterGraph {
+        self.ptr as *const _
+    }
+
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::AVFilterGraph {
+        self.ptr
+    }
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        unsafe {
+            Graph {
+                ptr: ffi::avfilter_graph_alloc(),
+                source: ptr::null_mut(),
+                sink: ptr::null_mut(),
+            }
+        }
+    }
+
+    /// 根据解码器参数添加输入端，时间基优先使用解码器的 pkt_timebase，未设置时为 1/采样率
+    pub fn add_abuffer(&mut self, decoder: &decoder::Audio) -> Result<()> {
+        let time_base = unsafe {
+            let pkt_timebase = (*decoder.as_ptr()).pkt_timebase;
+            if pkt_timebase.num > 0 && pkt_timebase.den > 0 {
+                Rational::from(pkt_timebase)
+            } else {
+                Rational::new(1, decoder.rate())
+            }
+        };
+
+        self.add_abuffer_with(decoder.format(), decoder.channel_layout(), decoder.rate(), time_base)
+    }
+
+    /// 按指定的采样格式、声道布局、采样率和时间基添加输入端
+    pub fn add_abuffer_with(
+        &mut self,
+        format: SampleFormat,
+        channel_layout: ChannelLayout,
+        rate: i32,
+        time_base: Rational,
+    ) -> Result<()> {
+        let args = format!(
+            "time_base={}/{}:sample_rate={}:sample_fmt={}:channel_layout={}",
+            time_base.num(),
+            time_base.den(),
+            rate,
+            format.name(),
+            channel_layout.or_default_order().describe(),
+        );
+
+        self.source = self.add("abuffer", SOURCE_NAME, &args)?;
+        Ok(())
+    }
+
+    /// 添加输出端，输出格式由滤镜链决定，可以在描述末尾使用 aformat 约束
+    pub fn add_abuffersink(&mut self) -> Result<()> {
+        self.sink = self.add("abuffersink", SINK_NAME, "")?;
+        Ok(())
+    }
+
+    /// 解析滤镜描述（如 "highpass=f=80,loudnorm,aresample=16000"），连接到输入端和输出端之间
+    pub fn parse(&mut self, spec: &str) -> Result<()> {
+        if self.source.is_null() || self.sink.is_null() {
+            return Err(Error::InvalidArgument);
+        }
+        let spec = c_string(spec)?;
+
+        unsafe {
+            let mut outputs = inout(SOURCE_NAME, self.source)?;
+            let mut inputs = match inout(SINK_NAME, self.sink) {
+                Ok(inputs) => inputs,
+                Err(e) => {
+                    ffi::avfilter_inout_free(&mut outputs);
+                    return Err(e);
+                }
+            };
+
+            let res = ffi::avfilter_graph_parse_ptr(self.as_mut_ptr(), spec.as_ptr(), &mut inputs, &mut outputs, ptr::null_mut());
+
+            ffi::avfilter_inout_free(&mut inputs);
+            ffi::avfilter_inout_free(&mut outputs);
+
+            match res {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 检查并配置滤镜图，需要在 parse 之后、push 之前调用
+    pub fn validate(&mut self) -> Result<()> {
+        unsafe {
+            match ffi::avfilter_graph_config(self.as_mut_ptr(), ptr::null_mut()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 向输入端写入一帧音频
+    pub fn push(&mut self, frame: &frame::Audio) -> Result<()> {
+        if self.source.is_null() {
+            return Err(Error::InvalidArgument);
+        }
+
+        unsafe {
+            match ffi::av_buffersrc_add_frame_flags(
+                self.source,
+                frame.as_ptr() as *mut _,
+                ffi::AV_BUFFERSRC_FLAG_KEEP_REF as _,
+            ) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 输入结束，之后可以从输出端取出滤镜缓存的剩余数据
+    pub fn flush(&mut self) -> Result<()> {
+        if self.source.is_null() {
+            return Err(Error::InvalidArgument);
+        }
+
+        unsafe {
+            match ffi::av_buffersrc_add_frame_flags(self.source, ptr::null_mut(), 0) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 从输出端取出一帧音频，暂无输出时返回 Error::Again，全部输出完毕后返回 Error::Eof
+    pub fn pull(&mut self, frame: &mut frame::Audio) -> Result<()> {
+        if self.sink.is_null() {
+            return Err(Error::InvalidArgument);
+        }
+
+        unsafe {
+            // 输出帧会被直接填充，需要先释放上一次的数据
+            ffi::av_frame_unref(frame.as_mut_ptr());
+            match ffi::av_buffersink_get_frame(self.sink, frame.as_mut_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    fn add(&mut self, filter: &str, name: &str, args: &str) -> Result<*mut ffi::AVFilterContext> {
+        let filter_name = c_string(filter)?;
+        let name = c_string(name)?;
+        let args = c_string(args)?;
+
+        unsafe {
+            let filter = ffi::avfilter_get_by_name(filter_name.as_ptr());
+            if filter.is_null() {
+                return Err(Error::FilterNotFound);
+            }
+
+            let mut ctx = ptr::null_mut();
+
+            match ffi::avfilter_graph_create_filter(
+                &mut ctx,
+                filter,
+                name.as_ptr(),
+                args.as_ptr(),
+                ptr::null_mut(),
+                self.as_mut_ptr(),
+            ) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(ctx),
+            }
+        }
+    }
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+impl Options for Graph {
+    unsafe fn as_options_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
+}
+
+impl Drop for Graph {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::avfilter_graph_free(&mut self.ptr);
+        }
+    }
+}
+
+unsafe fn inout(name: &str, ctx: *mut ffi::AVFilterContext) -> Result<*mut ffi::AVFilterInOut> {
+    let name = c_string(name)?;
+    let inout = ffi::avfilter_inout_alloc();
+    if inout.is_null() {
+        return Err(Error::NoMemory);
+    }
+
+    (*inout).name = ffi::av_strdup(name.as_ptr());
+    (*inout).filter_ctx = ctx;
+    (*inout).pad_idx = 0;
+    (*inout).next = ptr::null_mut();
+    Ok(inout)
+}
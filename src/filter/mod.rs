@@ -0,0 +1,3 @@
+pub mod graph;
+
+pub use self::graph::Graph;
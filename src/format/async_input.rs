@@ -0,0 +1,182 @@
+use std::io::{self, Read};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::format::input::{InputFormat, OpenOptions};
+use crate::format::io::IoContext;
+use crate::util::error::{Error, Result};
+use crate::util::frame;
+use crate::util::media;
+
+/// 每次从异步源读取的字节数
+const CHUNK_SIZE: usize = 32 * 1024;
+/// 读取线程与解码线程之间缓存的数据块个数
+const CHUNK_CAPACITY: usize = 8;
+/// 默认缓存的已解码帧数，消费方跟不上时解码线程阻塞等待
+const DEFAULT_FRAME_CAPACITY: usize = 8;
+
+/// 异步的解封装及解码，解码在 tokio 的阻塞线程池中进行
+///
+/// 异步读取任务把数据块交给解码线程，解码线程通过自定义 IO 打开输入并解码最合适的一路音频流，
+/// 解码出的帧通过有界通道返回，作为 Stream 消费。需要在 tokio 运行时中创建，丢弃后读取和解码随之停止
+pub struct AsyncInput {
+    frames: mpsc::Receiver<Result<frame::Audio>>,
+    reader: JoinHandle<()>,
+}
+
+impl AsyncInput {
+    /// 自动探测封装格式
+    pub fn new<R: AsyncRead + Send + Unpin + 'static>(source: R) -> Self {
+        AsyncInput::spawn(source, None, DEFAULT_FRAME_CAPACITY)
+    }
+
+    /// 指定封装格式，适用于裸流等无法探测的输入
+    pub fn with_format<R: AsyncRead + Send + Unpin + 'static>(source: R, format: InputFormat) -> Self {
+        AsyncInput::spawn(source, Some(format), DEFAULT_FRAME_CAPACITY)
+    }
+
+    /// 指定最多缓存的已解码帧数
+    pub fn with_capacity<R: AsyncRead + Send + Unpin + 'static>(
+        source: R,
+        format: Option<InputFormat>,
+        capacity: usize,
+    ) -> Self {
+        AsyncInput::spawn(source, format, capacity.max(1))
+    }
+
+    fn spawn<R: AsyncRead + Send + Unpin + 'static>(source: R, format: Option<InputFormat>, capacity: usize) -> Self {
+        let (chunk_tx, chunk_rx) = mpsc::channel(CHUNK_CAPACITY);
+        let (frame_tx, frames) = mpsc::channel(capacity);
+
+        let reader = tokio::spawn(read_chunks(source, chunk_tx));
+        tokio::task::spawn_blocking(move || decode(ChunkReader::new(chunk_rx), format, frame_tx));
+
+        AsyncInput { frames, reader }
+    }
+
+    /// 下一帧，解码结束后返回 None
+    pub async fn next_frame(&mut self) -> Option<Result<frame::Audio>> {
+        self.frames.recv().await
+    }
+}
+
+impl Stream for AsyncInput {
+    type Item = Result<frame::Audio>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.frames.poll_recv(cx)
+    }
+}
+
+impl Drop for AsyncInput {
+    fn drop(&mut self) {
+        // 停止读取后解码线程读到结束，发送帧失败时退出
+        self.reader.abort();
+    }
+}
+
+/// 空数据块表示输入正常结束，通道未收到它就关闭说明读取任务被中止
+async fn read_chunks<R: AsyncRead + Unpin>(mut source: R, tx: mpsc::Sender<io::Result<Vec<u8>>>) {
+    loop {
+        let mut chunk = vec![0; CHUNK_SIZE];
+        let result = match source.read(&mut chunk).await {
+            Ok(0) => {
+                let _ = tx.send(Ok(Vec::new())).await;
+                return;
+            }
+            Ok(n) => {
+                chunk.truncate(n);
+                Ok(chunk)
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => Err(e),
+        };
+
+        let failed = result.is_err();
+        if tx.send(result).await.is_err() || failed {
+            return;
+        }
+    }
+}
+
+fn decode(reader: ChunkReader, format: Option<InputFormat>, tx: mpsc::Sender<Result<frame::Audio>>) {
+    let mut opts = OpenOptions::new();
+    if let Some(format) = format {
+        opts = opts.format(format);
+    }
+
+    let mut input = match IoContext::reader(reader).and_then(|io| opts.open_io(io)) {
+        Ok((input, _)) => input,
+        Err(e) => {
+            let _ = tx.blocking_send(Err(e));
+            return;
+        }
+    };
+
+    let index = match input.streams().best(media::Type::Audio) {
+        Some(stream) => stream.index(),
+        None => {
+            let _ = tx.blocking_send(Err(Error::StreamNotFound));
+            return;
+        }
+    };
+
+    let frames = match input.decode_audio(index) {
+        Ok(frames) => frames,
+        Err(e) => {
+            let _ = tx.blocking_send(Err(e));
+            return;
+        }
+    };
+
+    for frame in frames {
+        // 通道已满时在此阻塞，消费方被丢弃时结束解码
+        if tx.blocking_send(frame).is_err() {
+            return;
+        }
+    }
+}
+
+/// 在解码线程中阻塞读取异步任务送来的数据块
+struct ChunkReader {
+    rx: mpsc::Receiver<io::Result<Vec<u8>>>,
+    chunk: Vec<u8>,
+    pos: usize,
+    ended: bool,
+}
+
+impl ChunkReader {
+    fn new(rx: mpsc::Receiver<io::Result<Vec<u8>>>) -> Self {
+        ChunkReader { rx, chunk: Vec::new(), pos: 0, ended: false }
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos >= self.chunk.len() {
+            if self.ended {
+                return Ok(0);
+            }
+
+            match self.rx.blocking_recv() {
+                Some(chunk) => {
+                    self.chunk = chunk?;
+                    self.pos = 0;
+                    self.ended = self.chunk.is_empty();
+                }
+                // 读取任务被中止或 panic，不能当作正常结束
+                None => return Err(io::ErrorKind::ConnectionAborted.into()),
+            }
+        }
+
+        let n = buf.len().min(self.chunk.len() - self.pos);
+        buf[..n].copy_from_slice(&self.chunk[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
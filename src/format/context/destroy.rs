@@ -1,4 +1,6 @@
 use crate::ffi;
+use crate::format::interrupt::Interrupt;
+use crate::format::io::IoContext;
 
 #[derive(Copy, Clone, Debug)]
 pub enum Mode {
@@ -9,11 +11,23 @@ pub enum Mode {
 pub struct Destroy {
     ptr: *mut ffi::AVFormatContext,
     mode: Mode,
+    // 自定义 IO 需要在格式上下文关闭之后释放
+    io: Option<IoContext>,
+    // 中断回调同样在格式上下文关闭之后释放
+    _interrupt: Option<Box<Interrupt>>,
 }
 
+// 只在 drop 时访问格式上下文
+unsafe impl Send for Destroy {}
+unsafe impl Sync for Destroy {}
+
 impl Destroy {
     pub fn new(ptr: *mut ffi::AVFormatContext, mode: Mode) -> Self {
-        Self { ptr, mode }
+        Self { ptr, mode, io: None, _interrupt: None }
+    }
+
+    pub fn with(ptr: *mut ffi::AVFormatContext, mode: Mode, io: Option<IoContext>, interrupt: Option<Box<Interrupt>>) -> Self {
+        Self { ptr, mode, io, _interrupt: interrupt }
     }
 }
 
@@ -23,10 +37,12 @@ impl Drop for Destroy {
             match self.mode {
                 Mode::Input => ffi::avformat_close_input(&mut self.ptr),
                 Mode::Output => {
-                    ffi::avio_close((*self.ptr).pb);
+                    if self.io.is_none() {
+                        ffi::avio_close((*self.ptr).pb);
+                    }
                     ffi::avformat_free_context(self.ptr);
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
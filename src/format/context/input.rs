@@ -1,11 +1,18 @@
-use std::mem;
-use std::ops::Deref;
+use std::ops::{Bound, Deref, RangeBounds};
+use libc::c_void;
 
+use crate::codec;
+use crate::codec::context::decoder;
 use crate::ffi;
 use crate::format::context::destroy;
 use crate::format::stream::Stream;
 use crate::format::input::InputFormat;
+use crate::format::interrupt::Interrupt;
+use crate::format::io::IoContext;
 use crate::format::packet::Packet;
+use crate::util::error::{Error, Result};
+use crate::util::frame;
+use crate::util::options::Options;
 
 use super::Context;
 
@@ -14,11 +21,17 @@ pub struct InputContext {
     ctx: Context,
 }
 
+unsafe impl Send for InputContext {}
+
 impl InputContext {
     pub unsafe fn wrap(ptr: *mut ffi::AVFormatContext) -> Self {
         InputContext { ptr, ctx: Context::wrap(ptr, destroy::Mode::Input) }
     }
 
+    pub unsafe fn wrap_with(ptr: *mut ffi::AVFormatContext, io: Option<IoContext>, interrupt: Option<Box<Interrupt>>) -> Self {
+        InputContext { ptr, ctx: Context::wrap_with(ptr, destroy::Mode::Input, io, interrupt) }
+    }
+
     pub unsafe fn as_ptr(&self) -> *const ffi::AVFormatContext {
         self.ptr as *const _
     }
@@ -37,6 +50,69 @@ impl InputContext {
     pub fn packets(&mut self) -> PacketIter {
         PacketIter::new(self)
     }
+
+    /// 解码指定音频流，逐帧返回解码结果，输入读完后自动排空解码器
+    pub fn decode_audio(&mut self, stream_index: usize) -> Result<FrameIter> {
+        if stream_index >= self.nb_streams() as usize {
+            return Err(Error::StreamNotFound);
+        }
+
+        let decoder = unsafe {
+            let stream = Stream::wrap(&self.ctx, stream_index as i32);
+            let parameters = stream.parameters();
+            if (*parameters.as_ptr()).codec_type != ffi::AVMediaType::AVMEDIA_TYPE_AUDIO {
+                return Err(Error::InvalidMediaType);
+            }
+
+            let mut context = codec::Context::parameters_to_context(parameters)?;
+            (*context.as_mut_ptr()).pkt_timebase = stream.time_base().into();
+            context.decoder().audio()?
+        };
+
+        Ok(FrameIter::new(self, decoder, stream_index as i32))
+    }
+
+    /// 定位到 ts（以 AV_TIME_BASE 为单位），实际位置落在 range 范围内；定位后需要调用解码器的 flush
+    pub fn seek<R: RangeBounds<i64>>(&mut self, ts: i64, range: R) -> Result<()> {
+        self.seek_file(-1, ts, range)
+    }
+
+    /// 按指定流定位，ts 和 range 以该流的时间基为单位
+    pub fn seek_stream<R: RangeBounds<i64>>(&mut self, stream_index: i32, ts: i64, range: R) -> Result<()> {
+        self.seek_file(stream_index, ts, range)
+    }
+
+    /// 定位到第 secs 秒，落在该时间点之前最近的可解码位置
+    pub fn seek_secs(&mut self, secs: f64) -> Result<()> {
+        let ts = (secs * f64::from(ffi::AV_TIME_BASE)) as i64;
+        self.seek(ts, ..=ts)
+    }
+
+    fn seek_file<R: RangeBounds<i64>>(&mut self, stream_index: i32, ts: i64, range: R) -> Result<()> {
+        let min_ts = match range.start_bound() {
+            Bound::Included(&v) => v,
+            Bound::Excluded(&v) => v.saturating_add(1),
+            Bound::Unbounded => i64::MIN,
+        };
+        let max_ts = match range.end_bound() {
+            Bound::Included(&v) => v,
+            Bound::Excluded(&v) => v.saturating_sub(1),
+            Bound::Unbounded => i64::MAX,
+        };
+
+        unsafe {
+            match ffi::avformat_seek_file(self.as_mut_ptr(), stream_index, min_ts, ts, max_ts, 0) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+impl Options for InputContext {
+    unsafe fn as_options_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
 }
 
 impl Deref for InputContext {
@@ -49,32 +125,107 @@ impl Deref for InputContext {
 
 pub struct PacketIter<'a> {
     context: &'a mut InputContext,
+    finished: bool,
 }
 
 impl<'a> PacketIter<'a> {
     pub fn new(context: &mut InputContext) -> PacketIter {
-        PacketIter { context }
+        PacketIter { context, finished: false }
     }
 }
 
 impl<'a> Iterator for PacketIter<'a> {
-    type Item = (Stream<'a>, Packet);
+    /// 包所属流的序号及包，迭代器持有上下文的可变借用，流信息需要在迭代前获取
+    ///
+    /// 损坏的包返回 Error::InvalidData 后可以继续迭代，其他错误（如中断、IO 错误）返回后迭代结束
+    type Item = Result<(usize, Packet)>;
+
     fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
         let mut packet = Packet::empty();
+        match packet.read(self.context) {
+            Ok(..) => Some(Ok((packet.stream_index() as usize, packet))),
+            Err(Error::Eof) => {
+                self.finished = true;
+                None
+            }
+            Err(Error::InvalidData) => Some(Err(Error::InvalidData)),
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
 
-        loop {
-            match packet.read(self.context) {
-                Ok(..) => unsafe {
-                    return Some((Stream::wrap(mem::transmute_copy(&self.context), packet.stream_index()), packet));
-                },
+/// 解码一路音频流的帧迭代器
+pub struct FrameIter<'a> {
+    context: &'a mut InputContext,
+    decoder: decoder::Audio,
+    stream_index: i32,
+    draining: bool,
+    finished: bool,
+}
+
+impl<'a> FrameIter<'a> {
+    pub fn new(context: &'a mut InputContext, decoder: decoder::Audio, stream_index: i32) -> FrameIter<'a> {
+        FrameIter { context, decoder, stream_index, draining: false, finished: false }
+    }
+
+    /// 当前使用的解码器
+    pub fn decoder(&self) -> &decoder::Audio {
+        &self.decoder
+    }
+}
+
+impl<'a> Iterator for FrameIter<'a> {
+    /// 与 PacketIter 一致，损坏的包返回 Error::InvalidData 后可以继续迭代，其他错误返回后迭代结束
+    type Item = Result<frame::Audio>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let mut frame = frame::Audio::empty();
+            match self.decoder.receive_frame(&mut frame) {
+                Ok(..) => return Some(Ok(frame)),
+                Err(Error::Again) if !self.draining => (),
+                Err(Error::Again) | Err(Error::Eof) => {
+                    self.finished = true;
+                    return None;
+                }
                 Err(e) => {
-                    if e.to_string() == "EOF".to_string() {
-                        return None;
-                    } else {
-                        ()
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+            }
+
+            // 解码器需要更多数据，读取本路流的下一个包
+            let mut packet = Packet::empty();
+            match packet.read(self.context) {
+                Ok(..) if packet.stream_index() != self.stream_index => (),
+                Ok(..) => {
+                    // 损坏的包只影响当前包，调用方可以选择跳过错误继续迭代
+                    if let Err(e) = self.decoder.send_packet(&packet) {
+                        return Some(Err(e));
+                    }
+                }
+                Err(Error::Eof) => {
+                    self.draining = true;
+                    if let Err(e) = self.decoder.send_eof() {
+                        self.finished = true;
+                        return Some(Err(e));
                     }
                 }
+                Err(Error::InvalidData) => return Some(Err(Error::InvalidData)),
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
             }
         }
+
+        None
     }
 }
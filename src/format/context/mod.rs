@@ -1,24 +1,40 @@
 pub mod input;
+pub mod output;
 pub mod destroy;
 
 use std::{fmt, ptr};
 use std::fmt::Formatter;
-use std::rc::Rc;
 use crate::ffi;
 use crate::util::media;
 
 use self::destroy::Destroy;
+use crate::format::interrupt::Interrupt;
+use crate::format::io::IoContext;
 use crate::format::stream::Stream;
 
 
 pub struct Context {
     ptr: *mut ffi::AVFormatContext,
-    dtor: Rc<Destroy>,
+    // 只负责在 drop 时关闭格式上下文
+    _dtor: Destroy,
 }
 
+// 通过 &self 只读取格式上下文，修改都需要 &mut self
+unsafe impl Send for Context {}
+unsafe impl Sync for Context {}
+
 impl Context {
     pub unsafe fn wrap(ptr: *mut ffi::AVFormatContext, mode: destroy::Mode) -> Self {
-        Self { ptr, dtor: Rc::new(Destroy::new(ptr, mode)) }
+        Self { ptr, _dtor: Destroy::new(ptr, mode) }
+    }
+
+    pub unsafe fn wrap_with(
+        ptr: *mut ffi::AVFormatContext,
+        mode: destroy::Mode,
+        io: Option<IoContext>,
+        interrupt: Option<Box<Interrupt>>,
+    ) -> Self {
+        Self { ptr, _dtor: Destroy::with(ptr, mode, io, interrupt) }
     }
 
     pub unsafe fn as_ptr(&self) -> *const ffi::AVFormatContext {
@@ -28,10 +44,6 @@ impl Context {
     pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::AVFormatContext {
         self.ptr
     }
-
-    pub unsafe fn destroy(&self) -> Rc<Destroy> {
-        Rc::clone(&self.dtor)
-    }
 }
 
 impl Context {
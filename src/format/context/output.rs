@@ -0,0 +1,128 @@
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use libc::c_void;
+
+use crate::ffi;
+use crate::format::context::destroy;
+use crate::format::output::OutputFormat;
+use crate::format::packet::Packet;
+use crate::format::stream::StreamMut;
+use crate::util::dict::Dictionary;
+use crate::util::error::{Error, Result};
+use crate::util::options::Options;
+
+use super::Context;
+
+pub struct OutputContext {
+    ptr: *mut ffi::AVFormatContext,
+    ctx: Context,
+}
+
+unsafe impl Send for OutputContext {}
+
+impl OutputContext {
+    pub unsafe fn wrap(ptr: *mut ffi::AVFormatContext) -> Self {
+        OutputContext { ptr, ctx: Context::wrap(ptr, destroy::Mode::Output) }
+    }
+
+    pub unsafe fn as_ptr(&self) -> *const ffi::AVFormatContext {
+        self.ptr as *const _
+    }
+
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::AVFormatContext {
+        self.ptr
+    }
+}
+
+impl OutputContext {
+    // 输出格式上下文
+    pub fn format(&self) -> OutputFormat {
+        unsafe { OutputFormat::wrap((*self.as_ptr()).oformat as *mut ffi::AVOutputFormat) }
+    }
+
+    /// 新增一路输出流，需要在 write_header 之前通过 set_codecpar、set_time_base 设置流参数
+    pub fn add_stream(&mut self) -> Result<StreamMut> {
+        unsafe {
+            let stream = ffi::avformat_new_stream(self.as_mut_ptr(), ptr::null());
+            if stream.is_null() {
+                return Err(Error::NoMemory);
+            }
+
+            Ok(StreamMut::wrap(&mut self.ctx, (*stream).index))
+        }
+    }
+
+    /// 获取可修改的流
+    pub fn stream_mut(&mut self, index: usize) -> Option<StreamMut> {
+        unsafe {
+            if index >= self.nb_streams() as usize {
+                None
+            } else {
+                Some(StreamMut::wrap(&mut self.ctx, index as i32))
+            }
+        }
+    }
+
+    /// 写入文件头，opt 为封装器的私有参数
+    pub fn write_header(&mut self, opt: Dictionary) -> Result<()> {
+        unsafe {
+            let mut dict = opt.into_raw();
+            let res = ffi::avformat_write_header(self.as_mut_ptr(), &mut dict);
+            Dictionary::from_raw(dict);
+            match res {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 直接写入数据包，由调用方保证数据包按 dts 递增的顺序写入
+    pub fn write_packet(&mut self, packet: &mut Packet) -> Result<()> {
+        unsafe {
+            match ffi::av_write_frame(self.as_mut_ptr(), packet.as_mut_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 写入数据包，由封装器负责在多路流之间进行交织
+    pub fn write_interleaved(&mut self, packet: &mut Packet) -> Result<()> {
+        unsafe {
+            match ffi::av_interleaved_write_frame(self.as_mut_ptr(), packet.as_mut_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 写入文件尾，刷新所有缓存的数据包
+    pub fn write_trailer(&mut self) -> Result<()> {
+        unsafe {
+            match ffi::av_write_trailer(self.as_mut_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+impl Options for OutputContext {
+    unsafe fn as_options_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
+}
+
+impl Deref for OutputContext {
+    type Target = Context;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ctx
+    }
+}
+
+impl DerefMut for OutputContext {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.ctx
+    }
+}
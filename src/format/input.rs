@@ -1,16 +1,29 @@
 use std::ffi::{CStr, CString};
+use std::io::{Read, Seek};
 use std::ptr;
 use std::str::from_utf8_unchecked;
-
-use anyhow::{anyhow, Result};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::ffi;
 use crate::format::context::input::InputContext;
+use crate::format::interrupt::Interrupt;
+use crate::format::io::IoContext;
+use crate::util::channel_layout::ChannelLayout;
+use crate::util::dict::Dictionary;
+use crate::util::error::{c_string, Error, Result};
+use crate::util::samplefmt::SampleFormat;
 
+#[derive(Copy, Clone)]
 pub struct InputFormat {
     ptr: *mut ffi::AVInputFormat,
 }
 
+// 指向 libavformat 中静态的解封装器描述
+unsafe impl Send for InputFormat {}
+unsafe impl Sync for InputFormat {}
+
 impl InputFormat {
     pub unsafe fn wrap(ptr: *mut ffi::AVInputFormat) -> Self {
         InputFormat { ptr }
@@ -63,8 +76,8 @@ impl InputFormat {
 
 /// 根据输入格式的简称查找 AVInputFormat
 pub fn find_input_format(name: &str) -> Option<InputFormat> {
+    let c_name = CString::new(name).ok()?;
     unsafe {
-        let c_name = CString::new(name).unwrap();
         let ptr = ffi::av_find_input_format(c_name.as_ptr() as *const libc::c_char) as *mut ffi::AVInputFormat;
         if ptr.is_null() {
             None
@@ -74,35 +87,301 @@ pub fn find_input_format(name: &str) -> Option<InputFormat> {
     }
 }
 
+/// 所有已注册解封装器的迭代器
+pub struct InputFormatIter {
+    opaque: *mut libc::c_void,
+}
+
+impl Iterator for InputFormatIter {
+    type Item = InputFormat;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let ptr = ffi::av_demuxer_iterate(&mut self.opaque);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(InputFormat::wrap(ptr as *mut _))
+            }
+        }
+    }
+}
+
+/// 所有已注册的解封装器
+pub fn demuxers() -> InputFormatIter {
+    InputFormatIter { opaque: ptr::null_mut() }
+}
+
+/// 根据数据开头的若干字节探测输入格式，返回格式和得分（最高 100），无法识别时返回 None
+pub fn probe(data: &[u8]) -> Option<(InputFormat, i32)> {
+    // 探测缓冲区末尾需要 AVPROBE_PADDING_SIZE 个 0
+    let mut buf = Vec::with_capacity(data.len() + ffi::AVPROBE_PADDING_SIZE as usize);
+    buf.extend_from_slice(data);
+    buf.resize(data.len() + ffi::AVPROBE_PADDING_SIZE as usize, 0);
+
+    let filename = CString::new("").unwrap();
+    unsafe {
+        let probe_data = ffi::AVProbeData {
+            filename: filename.as_ptr(),
+            buf: buf.as_mut_ptr(),
+            buf_size: data.len() as libc::c_int,
+            mime_type: ptr::null(),
+        };
+
+        let mut score = 0;
+        let ptr = ffi::av_probe_input_format3(&probe_data, 1, &mut score);
+        if ptr.is_null() || score <= 0 {
+            None
+        } else {
+            Some((InputFormat::wrap(ptr as *mut _), score))
+        }
+    }
+}
+
 /// 打开音频文件，自动推算input format
 pub fn open(filename: &str) -> Result<InputContext> {
-    open_input(filename, ptr::null_mut())
+    OpenOptions::new().open(filename).map(|(ctx, _)| ctx)
 }
 
 /// 打开一个输入流并读取标题。解码器没有打开。流必须用 avformat_close_input()关闭。
 pub fn open_with_format(filename: &str, input_format: InputFormat) -> Result<InputContext> {
-    unsafe {
-        open_input(filename, input_format.as_ptr())
+    OpenOptions::new().format(input_format).open(filename).map(|(ctx, _)| ctx)
+}
+
+/// 带解封装选项打开输入（如 s16le 的 sample_rate、ch_layout），同时返回未被使用的选项
+pub fn open_with(filename: &str, input_format: Option<InputFormat>, options: Dictionary) -> Result<(InputContext, Dictionary<'static>)> {
+    let mut opts = OpenOptions::new().options(options);
+    if let Some(input_format) = input_format {
+        opts = opts.format(input_format);
     }
+    opts.open(filename)
 }
 
-fn open_input(filename: &str, input_format: *const ffi::AVInputFormat) -> Result<InputContext> {
-    unsafe {
-        let mut ctx = ptr::null_mut();
-        let c_filename = CString::new(filename).unwrap();
-
-        match ffi::avformat_open_input(&mut ctx, c_filename.as_ptr(), input_format, ptr::null_mut()) {
-            0 => {
-                match ffi::avformat_find_stream_info(ctx, ptr::null_mut()) {
-                    r if r >= 0 => Ok(InputContext::wrap(ctx)),
-                    e => {
-                        ffi::avformat_close_input(&mut ctx);
-                        Err(anyhow!("find stream info failed: {}", e))
+/// 从可随机访问的读取器（如内存中的 Cursor<Vec<u8>>）打开输入，自动推算input format
+pub fn open_reader<R: Read + Seek + Send + 'static>(reader: R) -> Result<InputContext> {
+    open_io(IoContext::seekable_reader(reader)?, None)
+}
+
+/// 从只能顺序读取的读取器打开输入，无法 seek，部分需要回读的封装格式（如 mp4）可能打开失败
+pub fn open_unseekable_reader<R: Read + Send + 'static>(reader: R) -> Result<InputContext> {
+    open_io(IoContext::reader(reader)?, None)
+}
+
+/// 通过自定义 IO 打开输入，input_format 为空时自动推算
+pub fn open_io(io: IoContext, input_format: Option<InputFormat>) -> Result<InputContext> {
+    let mut opts = OpenOptions::new();
+    if let Some(input_format) = input_format {
+        opts = opts.format(input_format);
+    }
+    opts.open_io(io).map(|(ctx, _)| ctx)
+}
+
+/// 无文件头的原始音频格式，对应 FFmpeg 的 raw 解封装器
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PcmFormat {
+    U8,
+    S8,
+    S16LE,
+    S16BE,
+    S24LE,
+    S24BE,
+    S32LE,
+    S32BE,
+    F32LE,
+    F32BE,
+    F64LE,
+    F64BE,
+    /// G.711 μ-law
+    MuLaw,
+    /// G.711 A-law
+    ALaw,
+}
+
+impl PcmFormat {
+    /// 解封装器名称
+    pub fn demuxer_name(&self) -> &'static str {
+        match self {
+            PcmFormat::U8 => "u8",
+            PcmFormat::S8 => "s8",
+            PcmFormat::S16LE => "s16le",
+            PcmFormat::S16BE => "s16be",
+            PcmFormat::S24LE => "s24le",
+            PcmFormat::S24BE => "s24be",
+            PcmFormat::S32LE => "s32le",
+            PcmFormat::S32BE => "s32be",
+            PcmFormat::F32LE => "f32le",
+            PcmFormat::F32BE => "f32be",
+            PcmFormat::F64LE => "f64le",
+            PcmFormat::F64BE => "f64be",
+            PcmFormat::MuLaw => "mulaw",
+            PcmFormat::ALaw => "alaw",
+        }
+    }
+
+    /// 与采样格式对应的小端交织格式，planar、S64 等没有对应的 raw 解封装器
+    pub fn from_sample_format(format: SampleFormat) -> Option<PcmFormat> {
+        match format {
+            SampleFormat::U8 => Some(PcmFormat::U8),
+            SampleFormat::S16 => Some(PcmFormat::S16LE),
+            SampleFormat::S32 => Some(PcmFormat::S32LE),
+            SampleFormat::FLT => Some(PcmFormat::F32LE),
+            SampleFormat::DBL => Some(PcmFormat::F64LE),
+            _ => None,
+        }
+    }
+}
+
+/// 打开无文件头的 PCM 文件，采样按 format 交织存储（如 S16 对应 s16le）
+pub fn open_raw_pcm(filename: &str, format: SampleFormat, rate: i32, layout: ChannelLayout) -> Result<InputContext> {
+    let format = PcmFormat::from_sample_format(format).ok_or(Error::InvalidArgument)?;
+    open_raw(filename, format, rate, layout)
+}
+
+/// 从读取器打开无文件头的 PCM 数据
+pub fn open_raw_pcm_reader<R: Read + Send + 'static>(reader: R, format: SampleFormat, rate: i32, layout: ChannelLayout) -> Result<InputContext> {
+    let format = PcmFormat::from_sample_format(format).ok_or(Error::InvalidArgument)?;
+    open_raw_reader(reader, format, rate, layout)
+}
+
+/// 打开无文件头的音频文件，支持 μ-law、A-law 以及大端格式
+pub fn open_raw(filename: &str, format: PcmFormat, rate: i32, layout: ChannelLayout) -> Result<InputContext> {
+    raw_options(format, rate, &layout)?.open(filename).map(|(ctx, _)| ctx)
+}
+
+/// 从读取器打开无文件头的音频数据
+pub fn open_raw_reader<R: Read + Send + 'static>(reader: R, format: PcmFormat, rate: i32, layout: ChannelLayout) -> Result<InputContext> {
+    raw_options(format, rate, &layout)?.open_io(IoContext::reader(reader)?).map(|(ctx, _)| ctx)
+}
+
+/// raw 解封装器需要通过选项指定采样率和声道布局
+fn raw_options(format: PcmFormat, rate: i32, layout: &ChannelLayout) -> Result<OpenOptions<'static>> {
+    if rate <= 0 || layout.nb_channels() <= 0 {
+        return Err(Error::InvalidArgument);
+    }
+
+    let input_format = find_input_format(format.demuxer_name()).ok_or(Error::DemuxerNotFound)?;
+    let ch_layout = layout.clone().or_default_order().describe();
+
+    let mut options = Dictionary::new();
+    options.set("sample_rate", &rate.to_string())?;
+    options.set("ch_layout", &ch_layout)?;
+    Ok(OpenOptions::new().format(input_format).options(options))
+}
+
+/// 打开输入时的参数：输入格式、解封装选项、探测大小、分析时长和中断回调
+pub struct OpenOptions<'a> {
+    format: Option<InputFormat>,
+    options: Dictionary<'a>,
+    probe_size: Option<i64>,
+    analyze_duration: Option<Duration>,
+    interrupt: Option<Box<Interrupt>>,
+}
+
+impl<'a> OpenOptions<'a> {
+    pub fn new() -> Self {
+        OpenOptions {
+            format: None,
+            options: Dictionary::new(),
+            probe_size: None,
+            analyze_duration: None,
+            interrupt: None,
+        }
+    }
+
+    /// 指定输入格式，不指定时自动探测
+    pub fn format(mut self, input_format: InputFormat) -> Self {
+        self.format = Some(input_format);
+        self
+    }
+
+    /// 传给解封装器和协议的选项
+    pub fn options(mut self, options: Dictionary<'a>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 探测输入格式时最多读取的字节数
+    pub fn probe_size(mut self, bytes: i64) -> Self {
+        self.probe_size = Some(bytes);
+        self
+    }
+
+    /// 分析流信息时最多读取的时长
+    pub fn analyze_duration(mut self, duration: Duration) -> Self {
+        self.analyze_duration = Some(duration);
+        self
+    }
+
+    /// 中断回调，在阻塞 IO 期间被反复调用，返回 true 时中断
+    pub fn interrupt<F: FnMut() -> bool + Send + 'static>(mut self, callback: F) -> Self {
+        self.interrupt = Some(Box::new(Interrupt::new(callback)));
+        self
+    }
+
+    /// 由标志位控制中断，其他线程置为 true 后正在进行的打开或读取以 Error::Exit 返回
+    pub fn interrupt_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.interrupt = Some(Box::new(Interrupt::flag(flag)));
+        self
+    }
+
+    /// 打开文件或 URL，同时返回未被使用的选项
+    pub fn open(self, filename: &str) -> Result<(InputContext, Dictionary<'static>)> {
+        let filename = c_string(filename)?;
+        self.open_input(Some(&filename), None)
+    }
+
+    /// 通过自定义 IO 打开，同时返回未被使用的选项
+    pub fn open_io(self, io: IoContext) -> Result<(InputContext, Dictionary<'static>)> {
+        self.open_input(None, Some(io))
+    }
+
+    fn open_input(self, filename: Option<&CString>, mut io: Option<IoContext>) -> Result<(InputContext, Dictionary<'static>)> {
+        let OpenOptions { format, options, probe_size, analyze_duration, mut interrupt } = self;
+
+        unsafe {
+            let mut ctx = ffi::avformat_alloc_context();
+            if ctx.is_null() {
+                return Err(Error::NoMemory);
+            }
+
+            if let Some(io) = io.as_mut() {
+                (*ctx).pb = io.as_mut_ptr();
+                (*ctx).flags |= ffi::AVFMT_FLAG_CUSTOM_IO;
+            }
+            if let Some(probe_size) = probe_size {
+                (*ctx).probesize = probe_size;
+            }
+            if let Some(duration) = analyze_duration {
+                (*ctx).max_analyze_duration = duration.as_micros().min(i64::MAX as u128) as i64;
+            }
+            if let Some(interrupt) = interrupt.as_mut() {
+                interrupt.install(ctx);
+            }
+
+            let fmt = format.map_or(ptr::null(), |f| f.as_ptr());
+            let mut dict = options.into_raw();
+
+            // 打开失败时 ctx 由 avformat_open_input 释放
+            let res = ffi::avformat_open_input(&mut ctx, filename.map_or(ptr::null(), |f| f.as_ptr()), fmt, &mut dict);
+            let unused = Dictionary::from_raw(dict);
+            match res {
+                0 => {
+                    match ffi::avformat_find_stream_info(ctx, ptr::null_mut()) {
+                        r if r >= 0 => Ok((InputContext::wrap_with(ctx, io, interrupt), unused)),
+                        e => {
+                            ffi::avformat_close_input(&mut ctx);
+                            Err(Error::from(e))
+                        }
                     }
                 }
+                e => Err(Error::from(e))
             }
-            e => Err(anyhow!("open failed: {}", e))
         }
     }
 }
 
+impl<'a> Default for OpenOptions<'a> {
+    fn default() -> Self {
+        OpenOptions::new()
+    }
+}
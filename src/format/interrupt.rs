@@ -0,0 +1,40 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use libc::{c_int, c_void};
+
+use crate::ffi;
+
+/// 阻塞 IO 的中断回调，返回 true 时正在进行的打开、读取操作以 Error::Exit 返回
+pub struct Interrupt {
+    callback: Box<dyn FnMut() -> bool + Send>,
+}
+
+impl Interrupt {
+    pub fn new<F: FnMut() -> bool + Send + 'static>(callback: F) -> Self {
+        Interrupt { callback: Box::new(callback) }
+    }
+
+    /// 由标志位控制，其他线程置为 true 后中断
+    pub fn flag(flag: Arc<AtomicBool>) -> Self {
+        Interrupt::new(move || flag.load(Ordering::Relaxed))
+    }
+
+    /// 挂到格式上下文上，self 需要在格式上下文关闭之后释放
+    pub(crate) unsafe fn install(self: &mut Box<Self>, ctx: *mut ffi::AVFormatContext) {
+        (*ctx).interrupt_callback = ffi::AVIOInterruptCB {
+            callback: Some(interrupt_callback),
+            opaque: &mut **self as *mut Interrupt as *mut c_void,
+        };
+    }
+}
+
+unsafe extern "C" fn interrupt_callback(opaque: *mut c_void) -> c_int {
+    let interrupt = &mut *(opaque as *mut Interrupt);
+    // panic 不能跨越 FFI 边界，按中断处理
+    match panic::catch_unwind(AssertUnwindSafe(|| (interrupt.callback)())) {
+        Ok(false) => 0,
+        _ => 1,
+    }
+}
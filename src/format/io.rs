@@ -0,0 +1,136 @@
+use std::any::Any;
+use std::ffi::c_void;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use libc::c_int;
+
+use crate::ffi;
+use crate::util::error::{Error, Result};
+
+/// 自定义 IO 缓冲区大小
+const IO_BUFFER_SIZE: usize = 32 * 1024;
+
+/// 基于 Rust 读取器的 AVIOContext，读取器的生命周期与 IO 上下文一致
+pub struct IoContext {
+    ptr: *mut ffi::AVIOContext,
+    _opaque: Box<dyn Any + Send>,
+}
+
+// 读取器要求 Send，AVIOContext 只在持有者所在线程使用
+unsafe impl Send for IoContext {}
+
+impl IoContext {
+    pub unsafe fn as_ptr(&self) -> *const ffi::AVIOContext {
+        self.ptr as *const _
+    }
+
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::AVIOContext {
+        self.ptr
+    }
+}
+
+impl IoContext {
+    /// 可随机访问的读取器，支持 seek 及获取总长度
+    pub fn seekable_reader<R: Read + Seek + Send + 'static>(reader: R) -> Result<Self> {
+        Self::alloc(Box::new(reader), Some(read_packet::<R>), Some(seek::<R>))
+    }
+
+    /// 只能顺序读取的读取器，例如网络流
+    pub fn reader<R: Read + Send + 'static>(reader: R) -> Result<Self> {
+        Self::alloc(Box::new(reader), Some(read_packet::<R>), None)
+    }
+
+    fn alloc<R: Send + 'static>(
+        mut reader: Box<R>,
+        read: Option<unsafe extern "C" fn(*mut c_void, *mut u8, c_int) -> c_int>,
+        seek: Option<unsafe extern "C" fn(*mut c_void, i64, c_int) -> i64>,
+    ) -> Result<Self> {
+        unsafe {
+            // 读取器放在堆上，地址在 Box 移动后保持不变
+            let opaque = &mut *reader as *mut R as *mut c_void;
+
+            let buffer = ffi::av_malloc(IO_BUFFER_SIZE) as *mut u8;
+            if buffer.is_null() {
+                return Err(Error::NoMemory);
+            }
+
+            let ptr = ffi::avio_alloc_context(buffer, IO_BUFFER_SIZE as c_int, 0, opaque, read, None, seek);
+            if ptr.is_null() {
+                ffi::av_free(buffer as *mut c_void);
+                return Err(Error::NoMemory);
+            }
+
+            Ok(IoContext { ptr, _opaque: reader })
+        }
+    }
+}
+
+impl Drop for IoContext {
+    fn drop(&mut self) {
+        unsafe {
+            // 缓冲区可能已被 libavformat 重新分配，需要释放上下文中当前持有的缓冲区
+            ffi::av_freep(&mut (*self.ptr).buffer as *mut *mut u8 as *mut c_void);
+            ffi::avio_context_free(&mut self.ptr);
+        }
+    }
+}
+
+unsafe extern "C" fn read_packet<R: Read>(opaque: *mut c_void, buf: *mut u8, buf_size: c_int) -> c_int {
+    let reader = &mut *(opaque as *mut R);
+    let buf = slice::from_raw_parts_mut(buf, buf_size as usize);
+
+    // panic 不能跨越 FFI 边界，按 IO 错误处理
+    panic::catch_unwind(AssertUnwindSafe(|| read(reader, buf))).unwrap_or(ffi::AVERROR(libc::EIO))
+}
+
+fn read<R: Read>(reader: &mut R, buf: &mut [u8]) -> c_int {
+    loop {
+        match reader.read(buf) {
+            Ok(0) => return ffi::AVERROR_EOF,
+            Ok(n) => return n as c_int,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            // 暂无数据可读，由调用方稍后重试
+            Err(e) if e.kind() == ErrorKind::WouldBlock => return ffi::AVERROR(libc::EAGAIN),
+            Err(_) => return ffi::AVERROR(libc::EIO),
+        }
+    }
+}
+
+unsafe extern "C" fn seek<R: Seek>(opaque: *mut c_void, offset: i64, whence: c_int) -> i64 {
+    let reader = &mut *(opaque as *mut R);
+    panic::catch_unwind(AssertUnwindSafe(|| seek_to(reader, offset, whence))).unwrap_or(ffi::AVERROR(libc::EIO) as i64)
+}
+
+fn seek_to<R: Seek>(reader: &mut R, offset: i64, whence: c_int) -> i64 {
+    // AVSEEK_SIZE 表示查询总长度，不移动读取位置
+    if whence & ffi::AVSEEK_SIZE != 0 {
+        return match stream_len(reader) {
+            Ok(len) => len as i64,
+            Err(_) => ffi::AVERROR(libc::EIO) as i64,
+        };
+    }
+
+    let pos = match whence & !ffi::AVSEEK_FORCE {
+        libc::SEEK_SET => SeekFrom::Start(offset as u64),
+        libc::SEEK_CUR => SeekFrom::Current(offset),
+        libc::SEEK_END => SeekFrom::End(offset),
+        _ => return ffi::AVERROR(libc::EINVAL) as i64,
+    };
+
+    match reader.seek(pos) {
+        Ok(pos) => pos as i64,
+        Err(_) => ffi::AVERROR(libc::EIO) as i64,
+    }
+}
+
+fn stream_len<R: Seek>(reader: &mut R) -> std::io::Result<u64> {
+    let current = reader.stream_position()?;
+    let len = reader.seek(SeekFrom::End(0))?;
+    if current != len {
+        reader.seek(SeekFrom::Start(current))?;
+    }
+    Ok(len)
+}
+
@@ -1,5 +1,11 @@
 pub mod input;
+pub mod output;
 pub mod context;
+pub mod interrupt;
+pub mod io;
 pub mod packet;
 pub mod stream;
+pub mod streaming;
+#[cfg(feature = "tokio")]
+pub mod async_input;
 pub mod disposition;
@@ -0,0 +1,171 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::str::from_utf8_unchecked;
+
+use crate::codec::codec_id::CodecId;
+use crate::ffi;
+use crate::format::context::output::OutputContext;
+use crate::util::error::{c_string, Error, Result};
+
+pub struct OutputFormat {
+    ptr: *mut ffi::AVOutputFormat,
+}
+
+// 指向 libavformat 中静态的封装器描述
+unsafe impl Send for OutputFormat {}
+unsafe impl Sync for OutputFormat {}
+
+impl OutputFormat {
+    pub unsafe fn wrap(ptr: *mut ffi::AVOutputFormat) -> Self {
+        OutputFormat { ptr }
+    }
+
+    pub unsafe fn as_ptr(&self) -> *const ffi::AVOutputFormat {
+        self.ptr as *const _
+    }
+
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::AVOutputFormat {
+        self.ptr
+    }
+}
+
+impl OutputFormat {
+    pub fn name(&self) -> &str {
+        unsafe { from_utf8_unchecked(CStr::from_ptr((*self.as_ptr()).name).to_bytes()) }
+    }
+
+    pub fn long_name(&self) -> &str {
+        unsafe { from_utf8_unchecked(CStr::from_ptr((*self.as_ptr()).long_name).to_bytes()) }
+    }
+
+    pub fn extensions(&self) -> Vec<&str> {
+        unsafe {
+            let ptr = (*self.as_ptr()).extensions;
+            if ptr.is_null() {
+                Vec::new()
+            } else {
+                from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes())
+                    .split(',')
+                    .collect()
+            }
+        }
+    }
+
+    pub fn mime_types(&self) -> Vec<&str> {
+        unsafe {
+            let ptr = (*self.as_ptr()).mime_type;
+
+            if ptr.is_null() {
+                Vec::new()
+            } else {
+                from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes())
+                    .split(',')
+                    .collect()
+            }
+        }
+    }
+
+    /// 默认音频编码器
+    pub fn audio_codec(&self) -> CodecId {
+        unsafe { CodecId::from((*self.as_ptr()).audio_codec) }
+    }
+}
+
+/// 根据输出格式的简称查找 AVOutputFormat
+pub fn find_output_format(name: &str) -> Option<OutputFormat> {
+    let c_name = CString::new(name).ok()?;
+    unsafe {
+        let ptr = ffi::av_guess_format(c_name.as_ptr(), ptr::null(), ptr::null()) as *mut ffi::AVOutputFormat;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(OutputFormat::wrap(ptr))
+        }
+    }
+}
+
+/// 根据文件名（扩展名）和 MIME 类型推算输出格式
+pub fn guess_output_format(filename: Option<&str>, mime_type: Option<&str>) -> Option<OutputFormat> {
+    let c_filename = match filename {
+        Some(name) => Some(CString::new(name).ok()?),
+        None => None,
+    };
+    let c_mime_type = match mime_type {
+        Some(mime) => Some(CString::new(mime).ok()?),
+        None => None,
+    };
+
+    unsafe {
+        let ptr = ffi::av_guess_format(
+            ptr::null(),
+            c_filename.as_ref().map_or(ptr::null(), |name| name.as_ptr()),
+            c_mime_type.as_ref().map_or(ptr::null(), |mime| mime.as_ptr()),
+        ) as *mut ffi::AVOutputFormat;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(OutputFormat::wrap(ptr))
+        }
+    }
+}
+
+/// 所有已注册封装器的迭代器
+pub struct OutputFormatIter {
+    opaque: *mut libc::c_void,
+}
+
+impl Iterator for OutputFormatIter {
+    type Item = OutputFormat;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let ptr = ffi::av_muxer_iterate(&mut self.opaque);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(OutputFormat::wrap(ptr as *mut _))
+            }
+        }
+    }
+}
+
+/// 所有已注册的封装器
+pub fn muxers() -> OutputFormatIter {
+    OutputFormatIter { opaque: ptr::null_mut() }
+}
+
+/// 创建输出文件，format_name 为空时根据文件扩展名推算 output format
+pub fn create(filename: &str, format_name: Option<&str>) -> Result<OutputContext> {
+    let c_filename = c_string(filename)?;
+    let c_format_name = match format_name {
+        Some(name) => Some(c_string(name)?),
+        None => None,
+    };
+
+    unsafe {
+        let mut ctx = ptr::null_mut();
+
+        match ffi::avformat_alloc_output_context2(
+            &mut ctx,
+            ptr::null(),
+            c_format_name.as_ref().map_or(ptr::null(), |name| name.as_ptr()),
+            c_filename.as_ptr(),
+        ) {
+            e if e < 0 => Err(Error::from(e)),
+            _ => {
+                // 部分封装器（如 image2）自行管理 IO，不需要打开文件
+                if (*(*ctx).oformat).flags & ffi::AVFMT_NOFILE == 0 {
+                    match ffi::avio_open(&mut (*ctx).pb, c_filename.as_ptr(), ffi::AVIO_FLAG_WRITE) {
+                        e if e < 0 => {
+                            ffi::avformat_free_context(ctx);
+                            return Err(Error::from(e));
+                        }
+                        _ => (),
+                    }
+                }
+
+                Ok(OutputContext::wrap(ctx))
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use bitflags::bitflags;
+use crate::ffi;
+use libc::c_int;
+
+bitflags! {
+    pub struct Flags: c_int {
+        const KEY        = ffi::AV_PKT_FLAG_KEY;
+        const CORRUPT    = ffi::AV_PKT_FLAG_CORRUPT;
+        const DISCARD    = ffi::AV_PKT_FLAG_DISCARD;
+        const TRUSTED    = ffi::AV_PKT_FLAG_TRUSTED;
+        const DISPOSABLE = ffi::AV_PKT_FLAG_DISPOSABLE;
+    }
+}
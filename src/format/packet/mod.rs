@@ -1,17 +1,27 @@
+pub mod flag;
 pub mod side_data;
 pub mod side_data_type;
 
-use anyhow::{anyhow, Result};
+pub use self::flag::Flags;
+
+use std::{ptr, slice};
 use libc::c_int;
 
 use crate::ffi;
+use crate::util::error::{Error, Result};
+use crate::util::rational::Rational;
 use crate::format::context::input::InputContext;
+use self::side_data::{PacketSideData, SideDataIter};
+use self::side_data_type::PacketSideDataType;
 
 /// 音频数据包结构
 pub struct Packet {
     ptr: *mut ffi::AVPacket,
 }
 
+// 包独占 AVPacket，数据缓冲区的引用计数是原子的
+unsafe impl Send for Packet {}
+
 impl Packet {
     pub unsafe fn wrap(ptr: *mut ffi::AVPacket) -> Self {
         Packet { ptr }
@@ -44,6 +54,18 @@ impl Packet {
         }
     }
 
+    /// 拷贝一段数据创建数据包
+    #[inline]
+    pub fn copy(data: &[u8]) -> Self {
+        let mut pkt = Packet::new(data.len() as i32);
+        unsafe {
+            if !data.is_empty() && !(*pkt.as_ptr()).data.is_null() {
+                ptr::copy_nonoverlapping(data.as_ptr(), (*pkt.as_mut_ptr()).data, data.len());
+            }
+        }
+        pkt
+    }
+
     #[inline]
     pub fn size(&self) -> i32 {
         unsafe {
@@ -58,22 +80,183 @@ impl Packet {
         }
     }
 
+    #[inline]
+    pub fn set_stream_index(&mut self, value: i32) {
+        unsafe {
+            (*self.as_mut_ptr()).stream_index = value;
+        }
+    }
+
+    /// 显示时间戳，以所属流的时间基为单位
+    #[inline]
+    pub fn pts(&self) -> Option<i64> {
+        unsafe {
+            match (*self.as_ptr()).pts {
+                ffi::AV_NOPTS_VALUE => None,
+                pts => Some(pts)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn set_pts(&mut self, value: Option<i64>) {
+        unsafe {
+            (*self.as_mut_ptr()).pts = value.unwrap_or(ffi::AV_NOPTS_VALUE);
+        }
+    }
+
+    /// 解码时间戳，以所属流的时间基为单位
+    #[inline]
+    pub fn dts(&self) -> Option<i64> {
+        unsafe {
+            match (*self.as_ptr()).dts {
+                ffi::AV_NOPTS_VALUE => None,
+                dts => Some(dts)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn set_dts(&mut self, value: Option<i64>) {
+        unsafe {
+            (*self.as_mut_ptr()).dts = value.unwrap_or(ffi::AV_NOPTS_VALUE);
+        }
+    }
+
+    /// 时长，以所属流的时间基为单位，0 表示未知
+    #[inline]
+    pub fn duration(&self) -> i64 {
+        unsafe {
+            (*self.as_ptr()).duration
+        }
+    }
+
+    #[inline]
+    pub fn set_duration(&mut self, value: i64) {
+        unsafe {
+            (*self.as_mut_ptr()).duration = value;
+        }
+    }
+
+    /// 在输入中的字节偏移，-1 表示未知
+    #[inline]
+    pub fn position(&self) -> i64 {
+        unsafe {
+            (*self.as_ptr()).pos
+        }
+    }
+
+    #[inline]
+    pub fn set_position(&mut self, value: i64) {
+        unsafe {
+            (*self.as_mut_ptr()).pos = value;
+        }
+    }
+
+    #[inline]
+    pub fn flags(&self) -> Flags {
+        unsafe {
+            Flags::from_bits_truncate((*self.as_ptr()).flags)
+        }
+    }
+
+    #[inline]
+    pub fn set_flags(&mut self, value: Flags) {
+        unsafe {
+            (*self.as_mut_ptr()).flags = value.bits();
+        }
+    }
+
+    #[inline]
+    pub fn is_key(&self) -> bool {
+        self.flags().contains(Flags::KEY)
+    }
+
+    #[inline]
+    pub fn is_corrupt(&self) -> bool {
+        self.flags().contains(Flags::CORRUPT)
+    }
+
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        unsafe {
+            if (*self.as_ptr()).data.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts((*self.as_ptr()).data, self.size() as usize)
+            }
+        }
+    }
+
+    /// 可写的数据，数据被其它数据包共享时会先拷贝一份
+    #[inline]
+    pub fn data_mut(&mut self) -> Result<&mut [u8]> {
+        unsafe {
+            if (*self.as_ptr()).data.is_null() {
+                return Ok(&mut []);
+            }
+
+            match ffi::av_packet_make_writable(self.as_mut_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(slice::from_raw_parts_mut((*self.as_mut_ptr()).data, self.size() as usize)),
+            }
+        }
+    }
+
+    /// 将时间戳从 from 时间基转换到 to 时间基，例如从编码器时间基转换到输出流时间基
+    #[inline]
+    pub fn rescale_ts<S: Into<Rational>, D: Into<Rational>>(&mut self, from: S, to: D) {
+        unsafe {
+            ffi::av_packet_rescale_ts(self.as_mut_ptr(), from.into().into(), to.into().into());
+        }
+    }
+
+    /// 附加数据
+    #[inline]
+    pub fn side_data(&self) -> SideDataIter {
+        SideDataIter::new(self)
+    }
+
+    /// 添加一项附加数据
+    pub fn add_side_data(&mut self, kind: PacketSideDataType, data: &[u8]) -> Result<PacketSideData> {
+        unsafe {
+            let ptr = ffi::av_packet_new_side_data(self.as_mut_ptr(), kind.into(), data.len());
+            if ptr.is_null() {
+                return Err(Error::NoMemory);
+            }
+            ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len());
+
+            let elems = (*self.as_ptr()).side_data_elems;
+            Ok(PacketSideData::wrap((*self.as_mut_ptr()).side_data.offset((elems - 1) as isize)))
+        }
+    }
+
     #[inline]
     pub fn read(&mut self, format: &mut InputContext) -> Result<()> {
         unsafe {
             match ffi::av_read_frame(format.as_mut_ptr(), self.as_mut_ptr()) {
                 0 => Ok(()),
-                e if e == ffi::AVERROR_EOF => Err(anyhow!("EOF")),
-                e => Err(anyhow!("{}", e)),
+                e => Err(Error::from(e)),
             }
         }
     }
 }
 
+impl Clone for Packet {
+    /// 通过引用计数共享数据，不拷贝数据本身
+    fn clone(&self) -> Self {
+        let mut pkt = Packet::empty();
+        unsafe {
+            ffi::av_packet_ref(pkt.as_mut_ptr(), self.as_ptr());
+        }
+        pkt
+    }
+}
+
 impl Drop for Packet {
     fn drop(&mut self) {
         unsafe {
-            ffi::av_packet_unref(self.as_mut_ptr());
+            ffi::av_packet_free(&mut self.as_mut_ptr());
         }
     }
 }
@@ -31,4 +31,30 @@ impl<'a> PacketSideData<'a> {
             slice::from_raw_parts((*self.as_ptr()).data, (*self.as_ptr()).size)
         }
     }
-}
\ No newline at end of file
+}
+
+pub struct SideDataIter<'a> {
+    packet: &'a Packet,
+    cur: i32,
+}
+
+impl<'a> SideDataIter<'a> {
+    pub fn new(packet: &'a Packet) -> SideDataIter<'a> {
+        SideDataIter { packet, cur: 0 }
+    }
+}
+
+impl<'a> Iterator for SideDataIter<'a> {
+    type Item = PacketSideData<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            if self.cur >= (*self.packet.as_ptr()).side_data_elems {
+                return None;
+            }
+
+            self.cur += 1;
+            Some(PacketSideData::wrap((*self.packet.as_ptr()).side_data.offset((self.cur - 1) as isize)))
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::mem;
+use std::marker::PhantomData;
 use std::ops::{Deref};
 use libc::c_int;
 use crate::codec::codec_par::Parameters;
@@ -11,8 +11,6 @@ use crate::format::packet::side_data::PacketSideData;
 use crate::util::dict::{Dictionary, DictRef};
 use crate::util::discard::Discard;
 
-unsafe impl<'a> Send for Stream<'a> {}
-
 pub struct Stream<'a> {
     context: &'a Context,
     index: i32,
@@ -35,8 +33,13 @@ impl<'a> Stream<'a> {
 
     pub fn id(&self) -> i32 { unsafe { (*self.as_ptr()).id } }
 
+    /// 流参数的拷贝，不依赖格式上下文，可以移动到其他线程使用
     pub fn parameters(&self) -> Parameters {
-        unsafe { Parameters::wrap((*self.as_ptr()).codecpar, Some(self.context.destroy())) }
+        let mut parameters = Parameters::new();
+        unsafe {
+            ffi::avcodec_parameters_copy(parameters.as_mut_ptr(), (*self.as_ptr()).codecpar);
+        }
+        parameters
     }
 
     pub fn time_base(&self) -> Rational { unsafe { Rational::from((*self.as_ptr()).time_base) } }
@@ -115,23 +118,22 @@ impl<'a> Iterator for PacketSideDataIter<'a> {
 }
 
 
+/// 持有格式上下文的可变借用，期间只通过 AVStream 指针修改流
 pub struct StreamMut<'a> {
-    context: &'a mut Context,
-    index: i32,
     inner: Stream<'a>,
+    _marker: PhantomData<&'a mut Context>,
 }
 
 impl<'a> StreamMut<'a> {
     pub unsafe fn wrap(context: &mut Context, index: i32) -> StreamMut {
         StreamMut {
-            context: mem::transmute_copy(&context),
-            index,
-            inner: Stream::wrap(mem::transmute_copy(&context), index),
+            inner: Stream::wrap(context, index),
+            _marker: PhantomData,
         }
     }
 
     pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::AVStream {
-        *(*self.context.as_mut_ptr()).streams.add(self.index as usize)
+        self.inner.as_ptr() as *mut _
     }
 }
 
@@ -0,0 +1,313 @@
+use std::collections::VecDeque;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::sync::{Arc, Mutex};
+
+use crate::codec::context::{decoder, Context};
+use crate::ffi;
+use crate::format::context::input::InputContext;
+use crate::format::input::{InputFormat, OpenOptions};
+use crate::format::io::IoContext;
+use crate::format::packet::Packet;
+use crate::util::error::{Error, Result};
+use crate::util::frame;
+use crate::util::media;
+
+/// 打开输入前至少需要缓存的字节数，用于探测封装格式
+const DEFAULT_PROBE_SIZE: usize = 32 * 1024;
+/// 读取数据包前至少需要缓存的字节数，减少数据包跨越缓存末尾时的重读
+const DEFAULT_READ_AHEAD: usize = 8 * 1024;
+
+/// 推送式解码的结果
+pub enum Decoded {
+    /// 解码得到一帧音频
+    Frame(frame::Audio),
+    /// 缓存的数据不足，需要继续 push
+    NeedMoreData,
+    /// 已调用 end 且所有数据都已解码完毕
+    Finished,
+}
+
+/// 推送的数据，解封装完成的部分才会丢弃，读到一半的数据包可以回退重读
+#[derive(Default)]
+struct Buffer {
+    data: VecDeque<u8>,
+    /// data 第一个字节在输入中的位置
+    base: u64,
+    /// 下一次读取的位置
+    pos: u64,
+    ended: bool,
+    /// 输入是否已打开，打开过程中探测和 avformat_find_stream_info 遇到 EAGAIN 会原地重试
+    opened: bool,
+}
+
+impl Buffer {
+    fn end(&self) -> u64 {
+        self.base + self.data.len() as u64
+    }
+
+    /// 丢弃 offset 之前已经解封装完成的数据
+    fn discard(&mut self, offset: u64) {
+        let n = offset.saturating_sub(self.base).min(self.data.len() as u64);
+        self.data.drain(..n as usize);
+        self.base += n;
+    }
+}
+
+/// 从推送缓存中读取数据，缓存读完时：已结束或正在打开返回 0，否则返回 WouldBlock
+struct BufferReader(Arc<Mutex<Buffer>>);
+
+impl Read for BufferReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut buffer = self.0.lock().unwrap();
+        let offset = (buffer.pos - buffer.base) as usize;
+        if offset >= buffer.data.len() {
+            return if buffer.ended || !buffer.opened {
+                Ok(0)
+            } else {
+                Err(io::ErrorKind::WouldBlock.into())
+            };
+        }
+
+        let n = buf.len().min(buffer.data.len() - offset);
+        for (dst, src) in buf[..n].iter_mut().zip(buffer.data.range(offset..)) {
+            *dst = *src;
+        }
+        buffer.pos += n as u64;
+        Ok(n)
+    }
+}
+
+/// 只能在尚未丢弃的数据范围内移动，总长度未知
+impl Seek for BufferReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let mut buffer = self.0.lock().unwrap();
+        let target = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(delta) => u64::try_from(buffer.pos as i64 + delta).ok(),
+            SeekFrom::End(_) => None,
+        };
+
+        match target {
+            Some(target) if target >= buffer.base && target <= buffer.end() => {
+                buffer.pos = target;
+                Ok(target)
+            }
+            _ => Err(io::ErrorKind::Unsupported.into()),
+        }
+    }
+}
+
+/// 推送式的流式解封装及解码，适用于 websocket 等分块到达的音频数据
+///
+/// 调用方通过 push 写入任意大小的数据块，通过 receive 获取解码后的音频帧，数据不足时返回 NeedMoreData 而不会阻塞
+pub struct StreamingInput {
+    buffer: Arc<Mutex<Buffer>>,
+    format: Option<InputFormat>,
+    probe_size: usize,
+    read_ahead: usize,
+    input: Option<InputContext>,
+    decoder: Option<decoder::Audio>,
+    stream_index: i32,
+    draining: bool,
+}
+
+impl StreamingInput {
+    /// 自动推算input format
+    pub fn new() -> Self {
+        StreamingInput {
+            buffer: Arc::new(Mutex::new(Buffer::default())),
+            format: None,
+            probe_size: DEFAULT_PROBE_SIZE,
+            read_ahead: DEFAULT_READ_AHEAD,
+            input: None,
+            decoder: None,
+            stream_index: -1,
+            draining: false,
+        }
+    }
+
+    /// 指定input format，用于无法探测的格式（如裸 PCM）
+    pub fn with_format(format: InputFormat) -> Self {
+        let mut input = Self::new();
+        input.format = Some(format);
+        input
+    }
+
+    /// 打开输入前需要缓存的字节数
+    pub fn set_probe_size(&mut self, value: usize) {
+        self.probe_size = value;
+    }
+
+    /// 读取每个数据包前需要缓存的字节数，只用于减少重读；数据包跨越缓存末尾时会回退并等待更多数据
+    pub fn set_read_ahead(&mut self, value: usize) {
+        self.read_ahead = value;
+    }
+
+    /// 写入一块数据
+    pub fn push(&mut self, data: &[u8]) {
+        self.buffer.lock().unwrap().data.extend(data);
+    }
+
+    /// 数据写入完毕，之后的 receive 会解码剩余数据并排水
+    pub fn end(&mut self) {
+        self.buffer.lock().unwrap().ended = true;
+    }
+
+    /// 输入上下文，第一帧解码前为空
+    pub fn input(&self) -> Option<&InputContext> {
+        self.input.as_ref()
+    }
+
+    /// 音频解码器，第一帧解码前为空
+    pub fn decoder(&self) -> Option<&decoder::Audio> {
+        self.decoder.as_ref()
+    }
+
+    /// 获取下一帧解码后的音频
+    pub fn receive(&mut self) -> Result<Decoded> {
+        if self.input.is_none() {
+            if !self.ended() && self.buffered() < self.probe_size {
+                return Ok(Decoded::NeedMoreData);
+            }
+            self.open()?;
+        }
+
+        let mut frame = frame::Audio::empty();
+        loop {
+            match self.decoder.as_mut().unwrap().receive_frame(&mut frame) {
+                Ok(..) => return Ok(Decoded::Frame(frame)),
+                Err(Error::Again) => (),
+                Err(Error::Eof) => return Ok(Decoded::Finished),
+                Err(e) => return Err(e),
+            }
+
+            if self.draining {
+                return Ok(Decoded::Finished);
+            }
+
+            let ended = self.ended();
+            if !ended && self.buffered() < self.read_ahead {
+                return Ok(Decoded::NeedMoreData);
+            }
+
+            let mut packet = Packet::empty();
+            let input = self.input.as_mut().unwrap();
+            let start = tell(input);
+            let result = packet.read(input);
+
+            // 尚未结束时读到了缓存末尾，数据包可能被截断或被当作输入结束，回到包的起点等待更多数据
+            if !ended && (eof_reached(input) || matches!(result, Err(Error::Again) | Err(Error::Eof))) {
+                rewind(input, start)?;
+                return Ok(Decoded::NeedMoreData);
+            }
+
+            match result {
+                Ok(..) => {
+                    let consumed = tell(input);
+                    self.buffer.lock().unwrap().discard(consumed as u64);
+                    if packet.stream_index() == self.stream_index {
+                        self.decoder.as_mut().unwrap().send_packet(&packet)?;
+                    }
+                }
+                Err(Error::Eof) => {
+                    self.decoder.as_mut().unwrap().send_eof()?;
+                    self.draining = true;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn open(&mut self) -> Result<()> {
+        let mut io = IoContext::seekable_reader(BufferReader(Arc::clone(&self.buffer)))?;
+        unsafe {
+            // seek 只用于回退到数据包起点，对解封装器表现为不可随机访问的流
+            (*io.as_mut_ptr()).seekable = 0;
+        }
+
+        // 探测及读取流信息时留出 read_ahead 的余量，避免读到缓存末尾截断数据包
+        let ended = self.ended();
+        let probe_size = if ended {
+            self.buffered()
+        } else {
+            self.buffered().saturating_sub(self.read_ahead)
+        };
+        let mut opts = OpenOptions::new().probe_size(probe_size.max(32) as i64);
+        // 打开失败后可能还会重试，保留调用方指定的格式
+        if let Some(format) = self.format {
+            opts = opts.format(format);
+        }
+
+        let mut input = match opts.open_io(io) {
+            Ok((input, _)) => input,
+            Err(e) => {
+                // 下次从头重新探测
+                let mut buffer = self.buffer.lock().unwrap();
+                buffer.pos = buffer.base;
+                return Err(e);
+            }
+        };
+        self.buffer.lock().unwrap().opened = true;
+        if !ended {
+            // 打开过程中读空缓存时 IO 会被标记为结束，此时没有读到一半的包，可以直接清除
+            clear_io_error(&mut input);
+        }
+        let consumed = tell(&input);
+        self.buffer.lock().unwrap().discard(consumed as u64);
+
+        let stream = input.streams().best(media::Type::Audio).ok_or(Error::StreamNotFound)?;
+        let stream_index = stream.index() as i32;
+        let context = Context::parameters_to_context(stream.parameters())?;
+
+        self.decoder = Some(context.decoder().audio()?);
+        self.stream_index = stream_index;
+        self.input = Some(input);
+        Ok(())
+    }
+
+    /// 尚未解封装的字节数，包括 AVIOContext 中已读入的部分
+    fn buffered(&self) -> usize {
+        let consumed = self.input.as_ref().map(|input| tell(input) as u64);
+        let buffer = self.buffer.lock().unwrap();
+        buffer.end().saturating_sub(consumed.unwrap_or(buffer.base)) as usize
+    }
+
+    fn ended(&self) -> bool {
+        self.buffer.lock().unwrap().ended
+    }
+}
+
+impl Default for StreamingInput {
+    fn default() -> Self {
+        StreamingInput::new()
+    }
+}
+
+/// 已解封装到的位置
+fn tell(input: &InputContext) -> i64 {
+    unsafe { ffi::avio_seek((*input.as_ptr()).pb, 0, libc::SEEK_CUR) }
+}
+
+fn eof_reached(input: &InputContext) -> bool {
+    unsafe { (*(*input.as_ptr()).pb).eof_reached != 0 }
+}
+
+/// 回到 pos 重新读取，目标在 AVIO 缓冲区之外时通过 BufferReader 的 seek 回退
+fn rewind(input: &mut InputContext, pos: i64) -> Result<()> {
+    unsafe {
+        let res = ffi::avio_seek((*input.as_mut_ptr()).pb, pos, libc::SEEK_SET);
+        clear_io_error(input);
+        match res {
+            e if e < 0 => Err(Error::from(e as libc::c_int)),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn clear_io_error(input: &mut InputContext) {
+    unsafe {
+        let pb = (*input.as_mut_ptr()).pb;
+        (*pb).eof_reached = 0;
+        (*pb).error = 0;
+    }
+}
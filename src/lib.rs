@@ -5,5 +5,11 @@ pub use sys as ffi;
 pub mod format;
 pub mod codec;
 pub mod util;
+#[cfg(feature = "filter")]
+pub mod filter;
+#[cfg(feature = "software-resampling")]
+pub mod software;
+
+pub use util::error::Error;
 
 
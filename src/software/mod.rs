@@ -0,0 +1,2 @@
+#[cfg(feature = "software-resampling")]
+pub mod resampling;
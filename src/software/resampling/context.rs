@@ -0,0 +1,129 @@
+use std::ptr;
+
+use libc::c_int;
+
+use crate::ffi;
+use crate::util::channel_layout::ChannelLayout;
+use crate::util::error::{Error, Result};
+use crate::util::frame;
+use crate::util::samplefmt::SampleFormat;
+
+/// 音频重采样上下文，在采样格式、声道布局和采样率之间转换
+pub struct Context {
+    ptr: *mut ffi::SwrContext,
+    output_format: SampleFormat,
+    output_layout: ChannelLayout,
+    output_rate: i32,
+}
+
+impl Context {
+    pub unsafe fn as_ptr(&self) -> *const ffi::SwrContext {
+        self.ptr as *const _
+    }
+
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::SwrContext {
+        self.ptr
+    }
+}
+
+impl Context {
+    /// 创建并初始化重采样上下文
+    pub fn get(
+        src_format: SampleFormat,
+        src_channel_layout: ChannelLayout,
+        src_rate: i32,
+        dst_format: SampleFormat,
+        dst_channel_layout: ChannelLayout,
+        dst_rate: i32,
+    ) -> Result<Self> {
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            match ffi::swr_alloc_set_opts2(
+                &mut ptr,
+                dst_channel_layout.as_ptr(),
+                dst_format.into(),
+                dst_rate as c_int,
+                src_channel_layout.as_ptr(),
+                src_format.into(),
+                src_rate as c_int,
+                0,
+                ptr::null_mut(),
+            ) {
+                e if e < 0 => return Err(Error::from(e)),
+                _ => (),
+            }
+
+            match ffi::swr_init(ptr) {
+                e if e < 0 => {
+                    ffi::swr_free(&mut ptr);
+                    return Err(Error::from(e));
+                }
+                _ => (),
+            }
+
+            Ok(Context { ptr, output_format: dst_format, output_layout: dst_channel_layout, output_rate: dst_rate })
+        }
+    }
+
+    pub fn output_format(&self) -> SampleFormat {
+        self.output_format
+    }
+
+    pub fn output_channel_layout(&self) -> ChannelLayout {
+        self.output_layout.clone()
+    }
+
+    pub fn output_rate(&self) -> i32 {
+        self.output_rate
+    }
+
+    /// 重采样器内部缓存的延迟，以输出采样率下的采样数计
+    pub fn delay(&self) -> i64 {
+        unsafe { ffi::swr_get_delay(self.ptr, i64::from(self.output_rate)) }
+    }
+
+    /// 转换一帧音频，output 需要与输出参数一致；output 未分配缓冲区时按需分配
+    pub fn run(&mut self, input: &frame::Audio, output: &mut frame::Audio) -> Result<()> {
+        unsafe {
+            match ffi::swr_convert_frame(self.as_mut_ptr(), output.as_mut_ptr(), input.as_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 转换一帧音频，返回新分配的输出帧，输出的采样数可能少于输入，剩余部分保留在内部缓存中
+    pub fn convert(&mut self, input: &frame::Audio) -> Result<frame::Audio> {
+        let mut output = self.output_frame();
+        self.run(input, &mut output)?;
+        Ok(output)
+    }
+
+    /// 排空内部缓存的剩余采样，没有剩余时返回 None
+    pub fn flush(&mut self) -> Result<Option<frame::Audio>> {
+        let mut output = self.output_frame();
+        unsafe {
+            match ffi::swr_convert_frame(self.as_mut_ptr(), output.as_mut_ptr(), ptr::null()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ if output.samples() == 0 => Ok(None),
+                _ => Ok(Some(output)),
+            }
+        }
+    }
+
+    fn output_frame(&self) -> frame::Audio {
+        let mut output = frame::Audio::empty();
+        output.set_format(self.output_format);
+        output.set_sample_rate(self.output_rate);
+        output.set_channel_layout(self.output_layout.clone());
+        output
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::swr_free(&mut self.ptr);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod context;
+
+pub use self::context::Context;
@@ -1,37 +1,382 @@
+use std::ffi::CStr;
+use std::{fmt, mem, ptr};
+
+use libc::{c_char, c_int};
+
 use crate::ffi;
+use crate::util::error::{c_string, Error, Result};
+
+/// 声道描述的初始缓冲区长度，不够时按返回的长度重新分配
+const DESCRIBE_SIZE: usize = 64;
 
+/// 声道布局，持有一份独立的 AVChannelLayout，custom 布局的声道表随之复制和释放
 pub struct ChannelLayout {
-    ptr: *mut ffi::AVChannelLayout,
+    inner: ffi::AVChannelLayout,
 }
 
+// custom 布局的声道表为独占的副本
+unsafe impl Send for ChannelLayout {}
+unsafe impl Sync for ChannelLayout {}
+
 impl ChannelLayout {
-    pub unsafe fn wrap(ptr: *mut ffi::AVChannelLayout) -> Self {
-        ChannelLayout { ptr }
+    /// 从已有的 AVChannelLayout 复制一份
+    pub unsafe fn from_ptr(ptr: *const ffi::AVChannelLayout) -> Self {
+        let mut layout = ChannelLayout::empty();
+        ffi::av_channel_layout_copy(&mut layout.inner, ptr);
+        layout
     }
 
     pub unsafe fn as_ptr(&self) -> *const ffi::AVChannelLayout {
-        self.ptr as *const _
+        &self.inner as *const _
     }
 
     pub unsafe fn as_mut_ptr(&mut self) -> *mut ffi::AVChannelLayout {
-        self.ptr
+        &mut self.inner as *mut _
     }
 }
 
 impl ChannelLayout {
+    /// 未指定声道顺序、声道数为 0 的布局
+    pub fn empty() -> Self {
+        ChannelLayout { inner: unsafe { mem::zeroed() } }
+    }
+
+    /// 按声道数取默认布局，如 1 为 mono、2 为 stereo
     pub fn default(nb_channels: i32) -> Self {
-        let mut ch_layout = unsafe { std::mem::zeroed() };
+        let mut layout = ChannelLayout::empty();
+        unsafe {
+            ffi::av_channel_layout_default(layout.as_mut_ptr(), nb_channels);
+        }
+        layout
+    }
+
+    /// 由 AV_CH_* 掩码构造 native 布局
+    pub fn from_mask(mask: u64) -> Result<Self> {
+        let mut layout = ChannelLayout::empty();
         unsafe {
-            ffi::av_channel_layout_default(&mut ch_layout, nb_channels);
-            ChannelLayout::wrap(&mut ch_layout)
+            match ffi::av_channel_layout_from_mask(layout.as_mut_ptr(), mask) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(layout),
+            }
+        }
+    }
+
+    /// 由字符串构造，如 "stereo"、"5.1"、"FL+FR+LFE"、"4c"
+    pub fn from_string(value: &str) -> Result<Self> {
+        let value = c_string(value)?;
+        let mut layout = ChannelLayout::empty();
+        unsafe {
+            match ffi::av_channel_layout_from_string(layout.as_mut_ptr(), value.as_ptr()) {
+                e if e < 0 => Err(Error::from(e)),
+                _ => Ok(layout),
+            }
+        }
+    }
+
+    /// 未指定声道顺序时按声道数换成默认布局，其他布局保持不变
+    pub fn or_default_order(self) -> Self {
+        match self.order() {
+            ChannelOrder::Unspecified => ChannelLayout::default(self.nb_channels()),
+            _ => self,
         }
     }
 
     pub fn nb_channels(&self) -> i32 {
-        unsafe { (*self.as_ptr()).nb_channels }
+        self.inner.nb_channels
+    }
+
+    pub fn order(&self) -> ChannelOrder {
+        ChannelOrder::from(self.inner.order)
+    }
+
+    /// native 布局的声道掩码，其他顺序返回 None
+    pub fn mask(&self) -> Option<u64> {
+        match self.order() {
+            ChannelOrder::Native => Some(unsafe { self.inner.u.mask }),
+            _ => None,
+        }
+    }
+
+    /// 布局是否合法
+    pub fn is_valid(&self) -> bool {
+        unsafe { ffi::av_channel_layout_check(self.as_ptr()) == 1 }
+    }
+
+    /// 布局的字符串描述，如 "stereo"、"5.1(side)"
+    pub fn describe(&self) -> String {
+        let mut buf = vec![0 as c_char; DESCRIBE_SIZE];
+        unsafe {
+            let mut size = ffi::av_channel_layout_describe(self.as_ptr(), buf.as_mut_ptr(), buf.len());
+            if size > 0 && size as usize > buf.len() {
+                buf.resize(size as usize, 0);
+                size = ffi::av_channel_layout_describe(self.as_ptr(), buf.as_mut_ptr(), buf.len());
+            }
+
+            if size < 0 {
+                return String::new();
+            }
+
+            CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned()
+        }
+    }
+
+    /// 第 index 个声道，超出范围时返回 None
+    pub fn channel(&self, index: i32) -> Option<Channel> {
+        if index < 0 || index >= self.nb_channels() {
+            return None;
+        }
+
+        unsafe {
+            match self.order() {
+                ChannelOrder::Unspecified => Some(Channel::Unknown),
+                ChannelOrder::Native => Some(nth_bit(self.inner.u.mask, index)),
+                ChannelOrder::Custom => {
+                    // id 可能是 AVChannel 之外的取值，按整数读取
+                    let custom = self.inner.u.map.add(index as usize);
+                    Some(Channel::from(ptr::read(&(*custom).id as *const _ as *const c_int)))
+                }
+                ChannelOrder::Ambisonic => {
+                    // 先是所有 ambisonic 声道，之后是掩码中的非 diegetic 声道
+                    let mask = self.inner.u.mask;
+                    let ambisonic = self.nb_channels() - mask.count_ones() as i32;
+                    if index < ambisonic {
+                        Some(Channel::Ambisonic(index as u16))
+                    } else {
+                        Some(nth_bit(mask, index - ambisonic))
+                    }
+                }
+            }
+        }
+    }
+
+    /// 声道在布局中的位置，不存在时返回 None
+    pub fn index_of(&self, channel: Channel) -> Option<i32> {
+        (0..self.nb_channels()).find(|i| self.channel(*i) == Some(channel))
+    }
+
+    /// 按顺序列出所有声道
+    pub fn channels(&self) -> Vec<Channel> {
+        (0..self.nb_channels()).filter_map(|i| self.channel(i)).collect()
     }
 }
 
+/// 掩码中第 n 个置位所对应的声道
+fn nth_bit(mask: u64, n: i32) -> Channel {
+    let mut remaining = n;
+    for bit in 0..64 {
+        if mask & (1u64 << bit) != 0 {
+            if remaining == 0 {
+                return Channel::from(bit);
+            }
+            remaining -= 1;
+        }
+    }
+    Channel::None
+}
+
+impl Clone for ChannelLayout {
+    fn clone(&self) -> Self {
+        unsafe { ChannelLayout::from_ptr(self.as_ptr()) }
+    }
+}
+
+impl PartialEq for ChannelLayout {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { ffi::av_channel_layout_compare(self.as_ptr(), other.as_ptr()) == 0 }
+    }
+}
+
+impl fmt::Debug for ChannelLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
 
+impl Drop for ChannelLayout {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::av_channel_layout_uninit(self.as_mut_ptr());
+        }
+    }
+}
 
+/// 声道顺序
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ChannelOrder {
+    /// 只知道声道数，不知道声道含义
+    Unspecified,
+    /// 按 AV_CH_* 掩码的位顺序排列
+    Native,
+    /// 每个声道单独指定
+    Custom,
+    /// ambisonic 声道在前，之后可以跟随掩码描述的声道
+    Ambisonic,
+}
 
+impl From<ffi::AVChannelOrder> for ChannelOrder {
+    fn from(value: ffi::AVChannelOrder) -> Self {
+        match value {
+            ffi::AVChannelOrder::AV_CHANNEL_ORDER_UNSPEC => ChannelOrder::Unspecified,
+            ffi::AVChannelOrder::AV_CHANNEL_ORDER_NATIVE => ChannelOrder::Native,
+            ffi::AVChannelOrder::AV_CHANNEL_ORDER_CUSTOM => ChannelOrder::Custom,
+            ffi::AVChannelOrder::AV_CHANNEL_ORDER_AMBISONIC => ChannelOrder::Ambisonic,
+        }
+    }
+}
+
+impl From<ChannelOrder> for ffi::AVChannelOrder {
+    fn from(value: ChannelOrder) -> Self {
+        match value {
+            ChannelOrder::Unspecified => ffi::AVChannelOrder::AV_CHANNEL_ORDER_UNSPEC,
+            ChannelOrder::Native => ffi::AVChannelOrder::AV_CHANNEL_ORDER_NATIVE,
+            ChannelOrder::Custom => ffi::AVChannelOrder::AV_CHANNEL_ORDER_CUSTOM,
+            ChannelOrder::Ambisonic => ffi::AVChannelOrder::AV_CHANNEL_ORDER_AMBISONIC,
+        }
+    }
+}
+
+/// 单个声道，与 AVChannel 的取值一一对应
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Channel {
+    None,
+    FrontLeft,
+    FrontRight,
+    FrontCenter,
+    LowFrequency,
+    BackLeft,
+    BackRight,
+    FrontLeftOfCenter,
+    FrontRightOfCenter,
+    BackCenter,
+    SideLeft,
+    SideRight,
+    TopCenter,
+    TopFrontLeft,
+    TopFrontCenter,
+    TopFrontRight,
+    TopBackLeft,
+    TopBackCenter,
+    TopBackRight,
+    StereoLeft,
+    StereoRight,
+    WideLeft,
+    WideRight,
+    SurroundDirectLeft,
+    SurroundDirectRight,
+    LowFrequency2,
+    TopSideLeft,
+    TopSideRight,
+    BottomFrontCenter,
+    BottomFrontLeft,
+    BottomFrontRight,
+    /// 声道存在但不包含有效数据
+    Unused,
+    /// 声道含义未知
+    Unknown,
+    /// ambisonic 分量序号
+    Ambisonic(u16),
+    /// 当前版本未定义的取值
+    Other(i32),
+}
+
+/// AV_CHAN_UNUSED、AV_CHAN_UNKNOWN 与 ambisonic 分量的取值
+const CHAN_UNUSED: c_int = 0x200;
+const CHAN_UNKNOWN: c_int = 0x300;
+const CHAN_AMBISONIC_BASE: c_int = 0x400;
+const CHAN_AMBISONIC_END: c_int = 0x7ff;
+
+impl From<c_int> for Channel {
+    fn from(value: c_int) -> Self {
+        match value {
+            -1 => Channel::None,
+            0 => Channel::FrontLeft,
+            1 => Channel::FrontRight,
+            2 => Channel::FrontCenter,
+            3 => Channel::LowFrequency,
+            4 => Channel::BackLeft,
+            5 => Channel::BackRight,
+            6 => Channel::FrontLeftOfCenter,
+            7 => Channel::FrontRightOfCenter,
+            8 => Channel::BackCenter,
+            9 => Channel::SideLeft,
+            10 => Channel::SideRight,
+            11 => Channel::TopCenter,
+            12 => Channel::TopFrontLeft,
+            13 => Channel::TopFrontCenter,
+            14 => Channel::TopFrontRight,
+            15 => Channel::TopBackLeft,
+            16 => Channel::TopBackCenter,
+            17 => Channel::TopBackRight,
+            29 => Channel::StereoLeft,
+            30 => Channel::StereoRight,
+            31 => Channel::WideLeft,
+            32 => Channel::WideRight,
+            33 => Channel::SurroundDirectLeft,
+            34 => Channel::SurroundDirectRight,
+            35 => Channel::LowFrequency2,
+            36 => Channel::TopSideLeft,
+            37 => Channel::TopSideRight,
+            38 => Channel::BottomFrontCenter,
+            39 => Channel::BottomFrontLeft,
+            40 => Channel::BottomFrontRight,
+            CHAN_UNUSED => Channel::Unused,
+            CHAN_UNKNOWN => Channel::Unknown,
+            v if (CHAN_AMBISONIC_BASE..=CHAN_AMBISONIC_END).contains(&v) => {
+                Channel::Ambisonic((v - CHAN_AMBISONIC_BASE) as u16)
+            }
+            v => Channel::Other(v),
+        }
+    }
+}
+
+impl From<Channel> for c_int {
+    fn from(value: Channel) -> Self {
+        match value {
+            Channel::None => -1,
+            Channel::FrontLeft => 0,
+            Channel::FrontRight => 1,
+            Channel::FrontCenter => 2,
+            Channel::LowFrequency => 3,
+            Channel::BackLeft => 4,
+            Channel::BackRight => 5,
+            Channel::FrontLeftOfCenter => 6,
+            Channel::FrontRightOfCenter => 7,
+            Channel::BackCenter => 8,
+            Channel::SideLeft => 9,
+            Channel::SideRight => 10,
+            Channel::TopCenter => 11,
+            Channel::TopFrontLeft => 12,
+            Channel::TopFrontCenter => 13,
+            Channel::TopFrontRight => 14,
+            Channel::TopBackLeft => 15,
+            Channel::TopBackCenter => 16,
+            Channel::TopBackRight => 17,
+            Channel::StereoLeft => 29,
+            Channel::StereoRight => 30,
+            Channel::WideLeft => 31,
+            Channel::WideRight => 32,
+            Channel::SurroundDirectLeft => 33,
+            Channel::SurroundDirectRight => 34,
+            Channel::LowFrequency2 => 35,
+            Channel::TopSideLeft => 36,
+            Channel::TopSideRight => 37,
+            Channel::BottomFrontCenter => 38,
+            Channel::BottomFrontLeft => 39,
+            Channel::BottomFrontRight => 40,
+            Channel::Unused => CHAN_UNUSED,
+            Channel::Unknown => CHAN_UNKNOWN,
+            Channel::Ambisonic(n) => CHAN_AMBISONIC_BASE + n as c_int,
+            Channel::Other(v) => v,
+        }
+    }
+}
+
+impl Channel {
+    /// native 布局中对应的 AV_CH_* 掩码位，没有对应位时返回 None
+    pub fn mask(&self) -> Option<u64> {
+        match c_int::from(*self) {
+            v @ 0..=63 => Some(1 << v),
+            _ => None,
+        }
+    }
+}
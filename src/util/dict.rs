@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::{fmt, ptr};
 use std::str::from_utf8_unchecked;
-use anyhow::{anyhow, Result};
+use crate::util::error::{c_string, Error, Result};
 use crate::ffi;
 
 pub struct Dictionary<'a> {
@@ -109,7 +109,7 @@ impl<'a> DictRef<'a> {
 impl<'a> DictRef<'a> {
     pub fn get(&'a self, key: &str) -> Option<&'a str> {
         unsafe {
-            let key = CString::new(key).unwrap();
+            let key = CString::new(key).ok()?;
             let entry = ffi::av_dict_get(self.as_ptr(), key.as_ptr(), ptr::null_mut(), 0);
             if entry.is_null() {
                 None
@@ -164,13 +164,16 @@ impl<'a> DictMut<'a> {
 impl<'a> DictMut<'a> {
     pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
         unsafe {
-            let key = CString::new(key).unwrap();
-            let value = CString::new(value).unwrap();
+            let key = c_string(key)?;
+            let value = c_string(value)?;
             let mut ptr = self.as_mut_ptr();
 
             let ret = ffi::av_dict_set(&mut ptr, key.as_ptr(), value.as_ptr(), 0);
+            // 空字典在第一次 set 时才分配，需要更新指针
+            self.ptr = ptr;
+            self.imm = DictRef::wrap(ptr);
             if ret < 0 {
-                return Err(anyhow!("av_dict_set failed"));
+                Err(Error::from(ret))
             } else {
                 Ok(())
             }
@@ -0,0 +1,147 @@
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::str::from_utf8_unchecked;
+
+use libc::{c_char, c_int};
+
+use crate::ffi;
+
+/// av_strerror 错误描述的最大长度
+const ERROR_MAX_STRING_SIZE: usize = 64;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// 当前状态下暂时无法输出，需要继续输入（EAGAIN）
+    Again,
+    /// 已到达流的末尾
+    Eof,
+    /// 处理输入时发现无效数据
+    InvalidData,
+    /// 参数无效（EINVAL）
+    InvalidArgument,
+    /// 内存分配失败（ENOMEM）
+    NoMemory,
+    /// 读写失败（EIO）
+    Io,
+    /// 被中断回调终止
+    Exit,
+    BufferTooSmall,
+    Bug,
+    BsfNotFound,
+    DecoderNotFound,
+    DemuxerNotFound,
+    EncoderNotFound,
+    FilterNotFound,
+    MuxerNotFound,
+    OptionNotFound,
+    ProtocolNotFound,
+    StreamNotFound,
+    PatchWelcome,
+    External,
+    Experimental,
+    Unknown,
+
+    /// 编解码器未打开
+    NotOpen,
+    /// 媒体类型不符，例如对视频编解码器获取音频接口
+    InvalidMediaType,
+
+    /// 其它 FFmpeg 错误码
+    Other(c_int),
+}
+
+impl Error {
+    /// 对应的 FFmpeg 错误码，非 FFmpeg 错误时为 AVERROR(EINVAL)
+    pub fn code(&self) -> c_int {
+        match *self {
+            Error::Again => ffi::AVERROR(libc::EAGAIN),
+            Error::Eof => ffi::AVERROR_EOF,
+            Error::InvalidData => ffi::AVERROR_INVALIDDATA,
+            Error::InvalidArgument => ffi::AVERROR(libc::EINVAL),
+            Error::NoMemory => ffi::AVERROR(libc::ENOMEM),
+            Error::Io => ffi::AVERROR(libc::EIO),
+            Error::Exit => ffi::AVERROR_EXIT,
+            Error::BufferTooSmall => ffi::AVERROR_BUFFER_TOO_SMALL,
+            Error::Bug => ffi::AVERROR_BUG,
+            Error::BsfNotFound => ffi::AVERROR_BSF_NOT_FOUND,
+            Error::DecoderNotFound => ffi::AVERROR_DECODER_NOT_FOUND,
+            Error::DemuxerNotFound => ffi::AVERROR_DEMUXER_NOT_FOUND,
+            Error::EncoderNotFound => ffi::AVERROR_ENCODER_NOT_FOUND,
+            Error::FilterNotFound => ffi::AVERROR_FILTER_NOT_FOUND,
+            Error::MuxerNotFound => ffi::AVERROR_MUXER_NOT_FOUND,
+            Error::OptionNotFound => ffi::AVERROR_OPTION_NOT_FOUND,
+            Error::ProtocolNotFound => ffi::AVERROR_PROTOCOL_NOT_FOUND,
+            Error::StreamNotFound => ffi::AVERROR_STREAM_NOT_FOUND,
+            Error::PatchWelcome => ffi::AVERROR_PATCHWELCOME,
+            Error::External => ffi::AVERROR_EXTERNAL,
+            Error::Experimental => ffi::AVERROR_EXPERIMENTAL,
+            Error::Unknown => ffi::AVERROR_UNKNOWN,
+            Error::NotOpen | Error::InvalidMediaType => ffi::AVERROR(libc::EINVAL),
+            Error::Other(code) => code,
+        }
+    }
+}
+
+impl From<c_int> for Error {
+    fn from(value: c_int) -> Error {
+        match value {
+            e if e == ffi::AVERROR(libc::EAGAIN) => Error::Again,
+            e if e == ffi::AVERROR(libc::EINVAL) => Error::InvalidArgument,
+            e if e == ffi::AVERROR(libc::ENOMEM) => Error::NoMemory,
+            e if e == ffi::AVERROR(libc::EIO) => Error::Io,
+            ffi::AVERROR_EOF => Error::Eof,
+            ffi::AVERROR_INVALIDDATA => Error::InvalidData,
+            ffi::AVERROR_EXIT => Error::Exit,
+            ffi::AVERROR_BUFFER_TOO_SMALL => Error::BufferTooSmall,
+            ffi::AVERROR_BUG | ffi::AVERROR_BUG2 => Error::Bug,
+            ffi::AVERROR_BSF_NOT_FOUND => Error::BsfNotFound,
+            ffi::AVERROR_DECODER_NOT_FOUND => Error::DecoderNotFound,
+            ffi::AVERROR_DEMUXER_NOT_FOUND => Error::DemuxerNotFound,
+            ffi::AVERROR_ENCODER_NOT_FOUND => Error::EncoderNotFound,
+            ffi::AVERROR_FILTER_NOT_FOUND => Error::FilterNotFound,
+            ffi::AVERROR_MUXER_NOT_FOUND => Error::MuxerNotFound,
+            ffi::AVERROR_OPTION_NOT_FOUND => Error::OptionNotFound,
+            ffi::AVERROR_PROTOCOL_NOT_FOUND => Error::ProtocolNotFound,
+            ffi::AVERROR_STREAM_NOT_FOUND => Error::StreamNotFound,
+            ffi::AVERROR_PATCHWELCOME => Error::PatchWelcome,
+            ffi::AVERROR_EXTERNAL => Error::External,
+            ffi::AVERROR_EXPERIMENTAL => Error::Experimental,
+            ffi::AVERROR_UNKNOWN => Error::Unknown,
+            e => Error::Other(e),
+        }
+    }
+}
+
+impl From<Error> for c_int {
+    fn from(value: Error) -> c_int {
+        value.code()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::NotOpen => f.write_str("codec context not open"),
+            Error::InvalidMediaType => f.write_str("invalid media type"),
+            _ => f.write_str(&strerror(self.code())),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// 转换为 C 字符串，含有 NUL 字符的字符串不能传给 FFmpeg
+pub(crate) fn c_string(value: &str) -> Result<CString> {
+    CString::new(value).map_err(|_| Error::InvalidArgument)
+}
+
+/// 通过 av_strerror 获取错误码的描述
+fn strerror(code: c_int) -> String {
+    unsafe {
+        let mut buf = [0 as c_char; ERROR_MAX_STRING_SIZE];
+        ffi::av_strerror(code, buf.as_mut_ptr(), ERROR_MAX_STRING_SIZE);
+        from_utf8_unchecked(CStr::from_ptr(buf.as_ptr()).to_bytes()).to_string()
+    }
+}
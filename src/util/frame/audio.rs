@@ -4,6 +4,8 @@ use libc::c_int;
 use crate::util::frame::Frame;
 use crate::ffi;
 use crate::util::channel_layout::ChannelLayout;
+use crate::util::error::{Error, Result};
+use crate::util::rational::Rational;
 use crate::util::samplefmt::SampleFormat;
 
 pub struct Audio(Frame);
@@ -60,7 +62,7 @@ impl Audio {
     #[inline]
     pub fn set_channel_layout(&mut self, value: ChannelLayout) {
         unsafe {
-            (*self.as_mut_ptr()).ch_layout = *value.as_ptr();
+            ffi::av_channel_layout_copy(&mut (*self.as_mut_ptr()).ch_layout, value.as_ptr());
         }
     }
 
@@ -78,12 +80,12 @@ impl Audio {
 
     #[inline]
     pub fn channel_layout(&self) -> ChannelLayout {
-        unsafe { ChannelLayout::wrap(Box::into_raw(Box::new((*self.as_ptr()).ch_layout))) }
+        unsafe { ChannelLayout::from_ptr(&(*self.as_ptr()).ch_layout) }
     }
 
     #[inline]
     pub fn channels(&self) -> i32 {
-        self.channel_layout().nb_channels()
+        unsafe { (*self.as_ptr()).ch_layout.nb_channels }
     }
 
     #[inline]
@@ -125,6 +127,56 @@ impl Audio {
         }
     }
 
+    /// 按采样类型访问平面数据：packed 格式只有一个平面，包含 samples * channels 个采样；
+    /// planar 格式每个声道一个平面，包含 samples 个采样。T 为元组时表示一个交织的多声道采样
+    #[inline]
+    pub fn plane<T: Sample>(&self, index: usize) -> &[T] {
+        if index >= self.planes() {
+            panic!("out of bounds");
+        }
+
+        if !<T as Sample>::is_valid(self.format(), self.channels()) {
+            panic!("unsupported type");
+        }
+
+        unsafe {
+            slice::from_raw_parts(
+                *(*self.as_ptr()).extended_data.add(index) as *const T,
+                self.plane_len::<T>(),
+            )
+        }
+    }
+
+    #[inline]
+    pub fn plane_mut<T: Sample>(&mut self, index: usize) -> &mut [T] {
+        if index >= self.planes() {
+            panic!("out of bounds");
+        }
+
+        if !<T as Sample>::is_valid(self.format(), self.channels()) {
+            panic!("unsupported type");
+        }
+
+        unsafe {
+            slice::from_raw_parts_mut(
+                *(*self.as_mut_ptr()).extended_data.add(index) as *mut T,
+                self.plane_len::<T>(),
+            )
+        }
+    }
+
+    /// 单个平面中 T 的个数
+    #[inline]
+    fn plane_len<T: Sample>(&self) -> usize {
+        let samples = if self.is_packed() {
+            self.samples() as usize * self.channels() as usize
+        } else {
+            self.samples() as usize
+        };
+
+        samples * self.format().bytes() / mem::size_of::<T>()
+    }
+
     #[inline]
     pub fn data(&self, index: usize) -> &[u8] {
         if index >= self.planes() {
@@ -140,6 +192,158 @@ impl Audio {
     }
 }
 
+impl Audio {
+    /// 由交织排列的采样构造音频帧，采样格式由 T 决定（如 i16 对应 S16），
+    /// pts 从 0 开始，时间基为 1/采样率
+    pub fn from_interleaved<T: Primitive>(data: &[T], layout: ChannelLayout, rate: i32) -> Result<Audio> {
+        let channels = layout.nb_channels();
+        if channels <= 0 || data.len() % channels as usize != 0 || !T::is_valid(T::PACKED, channels) {
+            return Err(Error::InvalidArgument);
+        }
+
+        let samples = (data.len() / channels as usize) as i32;
+        let mut frame = Audio::with_samples(T::PACKED, samples, layout, rate)?;
+        if samples > 0 {
+            frame.plane_mut::<T>(0).copy_from_slice(data);
+        }
+        Ok(frame)
+    }
+
+    /// 由每个声道一个的采样切片构造 planar 音频帧，各声道的采样数需要一致
+    pub fn from_planes<T: Primitive>(planes: &[&[T]], layout: ChannelLayout, rate: i32) -> Result<Audio> {
+        let channels = layout.nb_channels();
+        if channels <= 0 || planes.len() != channels as usize || !T::is_valid(T::PLANAR, channels) {
+            return Err(Error::InvalidArgument);
+        }
+
+        let samples = planes[0].len();
+        if planes.iter().any(|plane| plane.len() != samples) {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut frame = Audio::with_samples(T::PLANAR, samples as i32, layout, rate)?;
+        if samples > 0 {
+            for (i, plane) in planes.iter().enumerate() {
+                frame.plane_mut::<T>(i).copy_from_slice(plane);
+            }
+        }
+        Ok(frame)
+    }
+
+    fn with_samples(format: SampleFormat, samples: i32, layout: ChannelLayout, rate: i32) -> Result<Audio> {
+        if rate <= 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut frame = Audio::new(format, samples, layout);
+        if samples > 0 && frame.is_empty() {
+            return Err(Error::NoMemory);
+        }
+
+        frame.set_sample_rate(rate);
+        frame.set_pts(Some(0));
+        frame.set_time_base(Rational::new(1, rate));
+        Ok(frame)
+    }
+
+    /// 转换为交织排列的采样，长度为 samples * channels，采样值按 T 的取值范围缩放
+    pub fn to_interleaved<T: Primitive>(&self) -> Vec<T> {
+        if self.planes() == 0 {
+            return Vec::new();
+        }
+
+        if self.is_packed() {
+            return self.plane_as::<T>(0);
+        }
+
+        let planes = self.to_planar::<T>();
+        let samples = self.samples() as usize;
+        let mut data = Vec::with_capacity(samples * planes.len());
+        for i in 0..samples {
+            for plane in &planes {
+                data.push(plane[i]);
+            }
+        }
+        data
+    }
+
+    /// 转换为按声道分开的采样，每个声道 samples 个，采样值按 T 的取值范围缩放
+    pub fn to_planar<T: Primitive>(&self) -> Vec<Vec<T>> {
+        if self.planes() == 0 {
+            return Vec::new();
+        }
+
+        if self.is_planar() {
+            return (0..self.planes()).map(|i| self.plane_as::<T>(i)).collect();
+        }
+
+        let channels = self.channels() as usize;
+        let data = self.plane_as::<T>(0);
+        (0..channels)
+            .map(|c| data.iter().skip(c).step_by(channels).copied().collect())
+            .collect()
+    }
+
+    /// 转换为指定采样格式的新帧，声道布局、采样率和时间戳保持不变
+    pub fn convert(&self, format: SampleFormat) -> Result<Audio> {
+        if format == SampleFormat::NONE {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut converted = Audio::new(format, self.samples(), self.channel_layout());
+        if self.samples() > 0 && converted.is_empty() {
+            return Err(Error::NoMemory);
+        }
+
+        unsafe {
+            ffi::av_frame_copy_props(converted.as_mut_ptr(), self.as_ptr());
+        }
+        converted.set_sample_rate(self.sample_rate());
+
+        if self.samples() > 0 {
+            match format {
+                SampleFormat::U8 | SampleFormat::U8P => converted.fill::<u8>(self),
+                SampleFormat::S16 | SampleFormat::S16P => converted.fill::<i16>(self),
+                SampleFormat::S32 | SampleFormat::S32P => converted.fill::<i32>(self),
+                SampleFormat::S64 | SampleFormat::S64P => converted.fill::<i64>(self),
+                SampleFormat::FLT | SampleFormat::FLTP => converted.fill::<f32>(self),
+                SampleFormat::DBL | SampleFormat::DBLP => converted.fill::<f64>(self),
+                SampleFormat::NONE => unreachable!(),
+            }
+        }
+
+        Ok(converted)
+    }
+
+    /// 读取一个平面并转换为 T
+    fn plane_as<T: Primitive>(&self, index: usize) -> Vec<T> {
+        match self.format() {
+            SampleFormat::U8 | SampleFormat::U8P => cast(self.plane::<u8>(index)),
+            SampleFormat::S16 | SampleFormat::S16P => cast(self.plane::<i16>(index)),
+            SampleFormat::S32 | SampleFormat::S32P => cast(self.plane::<i32>(index)),
+            SampleFormat::S64 | SampleFormat::S64P => cast(self.plane::<i64>(index)),
+            SampleFormat::FLT | SampleFormat::FLTP => cast(self.plane::<f32>(index)),
+            SampleFormat::DBL | SampleFormat::DBLP => cast(self.plane::<f64>(index)),
+            SampleFormat::NONE => Vec::new(),
+        }
+    }
+
+    /// 用 src 的采样填充当前帧，T 需要与当前帧的采样格式一致
+    fn fill<T: Primitive>(&mut self, src: &Audio) {
+        if self.is_packed() {
+            self.plane_mut::<T>(0).copy_from_slice(&src.to_interleaved::<T>());
+        } else {
+            for (i, plane) in src.to_planar::<T>().iter().enumerate() {
+                self.plane_mut::<T>(i).copy_from_slice(plane);
+            }
+        }
+    }
+}
+
+fn cast<S: Primitive, T: Primitive>(data: &[S]) -> Vec<T> {
+    data.iter().map(|s| T::from_f64(s.to_f64())).collect()
+}
+
 impl Deref for Audio {
     type Target = Frame;
     fn deref(&self) -> &Self::Target {
@@ -184,42 +388,42 @@ unsafe impl Sample for u8 {
 unsafe impl Sample for (u8, u8) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 2 && format == SampleFormat::U8P
+        channels == 2 && format == SampleFormat::U8
     }
 }
 
 unsafe impl Sample for (u8, u8, u8) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 3 && format == SampleFormat::U8P
+        channels == 3 && format == SampleFormat::U8
     }
 }
 
 unsafe impl Sample for (u8, u8, u8, u8) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 4 && format == SampleFormat::U8P
+        channels == 4 && format == SampleFormat::U8
     }
 }
 
 unsafe impl Sample for (u8, u8, u8, u8, u8) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 5 && format == SampleFormat::U8P
+        channels == 5 && format == SampleFormat::U8
     }
 }
 
 unsafe impl Sample for (u8, u8, u8, u8, u8, u8) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 6 && format == SampleFormat::U8P
+        channels == 6 && format == SampleFormat::U8
     }
 }
 
 unsafe impl Sample for (u8, u8, u8, u8, u8, u8, u8) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 7 && format == SampleFormat::U8P
+        channels == 7 && format == SampleFormat::U8
     }
 }
 
@@ -233,42 +437,42 @@ unsafe impl Sample for i16 {
 unsafe impl Sample for (i16, i16) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 2 && format == SampleFormat::S16P
+        channels == 2 && format == SampleFormat::S16
     }
 }
 
 unsafe impl Sample for (i16, i16, i16) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 3 && format == SampleFormat::S16P
+        channels == 3 && format == SampleFormat::S16
     }
 }
 
 unsafe impl Sample for (i16, i16, i16, i16) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 4 && format == SampleFormat::S16P
+        channels == 4 && format == SampleFormat::S16
     }
 }
 
 unsafe impl Sample for (i16, i16, i16, i16, i16) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 5 && format == SampleFormat::S16P
+        channels == 5 && format == SampleFormat::S16
     }
 }
 
 unsafe impl Sample for (i16, i16, i16, i16, i16, i16) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 6 && format == SampleFormat::S16P
+        channels == 6 && format == SampleFormat::S16
     }
 }
 
 unsafe impl Sample for (i16, i16, i16, i16, i16, i16, i16) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 7 && format == SampleFormat::S16P
+        channels == 7 && format == SampleFormat::S16
     }
 }
 
@@ -282,42 +486,42 @@ unsafe impl Sample for i32 {
 unsafe impl Sample for (i32, i32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 2 && format == SampleFormat::S32P
+        channels == 2 && format == SampleFormat::S32
     }
 }
 
 unsafe impl Sample for (i32, i32, i32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 3 && format == SampleFormat::S32P
+        channels == 3 && format == SampleFormat::S32
     }
 }
 
 unsafe impl Sample for (i32, i32, i32, i32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 4 && format == SampleFormat::S32P
+        channels == 4 && format == SampleFormat::S32
     }
 }
 
 unsafe impl Sample for (i32, i32, i32, i32, i32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 5 && format == SampleFormat::S32P
+        channels == 5 && format == SampleFormat::S32
     }
 }
 
 unsafe impl Sample for (i32, i32, i32, i32, i32, i32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 6 && format == SampleFormat::S32P
+        channels == 6 && format == SampleFormat::S32
     }
 }
 
 unsafe impl Sample for (i32, i32, i32, i32, i32, i32, i32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 7 && format == SampleFormat::S32P
+        channels == 7 && format == SampleFormat::S32
     }
 }
 
@@ -330,42 +534,42 @@ unsafe impl Sample for i64 {
 unsafe impl Sample for (i64, i64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 2 && format == SampleFormat::S64P
+        channels == 2 && format == SampleFormat::S64
     }
 }
 
 unsafe impl Sample for (i64, i64, i64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 3 && format == SampleFormat::S64P
+        channels == 3 && format == SampleFormat::S64
     }
 }
 
 unsafe impl Sample for (i64, i64, i64, i64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 4 && format == SampleFormat::S64P
+        channels == 4 && format == SampleFormat::S64
     }
 }
 
 unsafe impl Sample for (i64, i64, i64, i64, i64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 5 && format == SampleFormat::S64P
+        channels == 5 && format == SampleFormat::S64
     }
 }
 
 unsafe impl Sample for (i64, i64, i64, i64, i64, i64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 6 && format == SampleFormat::S64P
+        channels == 6 && format == SampleFormat::S64
     }
 }
 
 unsafe impl Sample for (i64, i64, i64, i64, i64, i64, i64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 7 && format == SampleFormat::S64P
+        channels == 7 && format == SampleFormat::S64
     }
 }
 
@@ -379,42 +583,42 @@ unsafe impl Sample for f32 {
 unsafe impl Sample for (f32, f32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 2 && format == SampleFormat::FLTP
+        channels == 2 && format == SampleFormat::FLT
     }
 }
 
 unsafe impl Sample for (f32, f32, f32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 3 && format == SampleFormat::FLTP
+        channels == 3 && format == SampleFormat::FLT
     }
 }
 
 unsafe impl Sample for (f32, f32, f32, f32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 4 && format == SampleFormat::FLTP
+        channels == 4 && format == SampleFormat::FLT
     }
 }
 
 unsafe impl Sample for (f32, f32, f32, f32, f32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 5 && format == SampleFormat::FLTP
+        channels == 5 && format == SampleFormat::FLT
     }
 }
 
 unsafe impl Sample for (f32, f32, f32, f32, f32, f32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 6 && format == SampleFormat::FLTP
+        channels == 6 && format == SampleFormat::FLT
     }
 }
 
 unsafe impl Sample for (f32, f32, f32, f32, f32, f32, f32) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 7 && format == SampleFormat::FLTP
+        channels == 7 && format == SampleFormat::FLT
     }
 }
 
@@ -428,43 +632,142 @@ unsafe impl Sample for f64 {
 unsafe impl Sample for (f64, f64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 2 && format == SampleFormat::DBLP
+        channels == 2 && format == SampleFormat::DBL
     }
 }
 
 unsafe impl Sample for (f64, f64, f64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 3 && format == SampleFormat::DBLP
+        channels == 3 && format == SampleFormat::DBL
     }
 }
 
 unsafe impl Sample for (f64, f64, f64, f64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 4 && format == SampleFormat::DBLP
+        channels == 4 && format == SampleFormat::DBL
     }
 }
 
 unsafe impl Sample for (f64, f64, f64, f64, f64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 5 && format == SampleFormat::DBLP
+        channels == 5 && format == SampleFormat::DBL
     }
 }
 
 unsafe impl Sample for (f64, f64, f64, f64, f64, f64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 6 && format == SampleFormat::DBLP
+        channels == 6 && format == SampleFormat::DBL
     }
 }
 
 unsafe impl Sample for (f64, f64, f64, f64, f64, f64, f64) {
     #[inline(always)]
     fn is_valid(format: SampleFormat, channels: i32) -> bool {
-        channels == 7 && format == SampleFormat::DBLP
+        channels == 7 && format == SampleFormat::DBL
+    }
+}
+
+/// 单个采样的基本类型，整数和浮点之间按 [-1.0, 1.0] 归一化后转换
+pub trait Primitive: Sample + Copy {
+    /// 对应的 packed 与 planar 采样格式
+    const PACKED: SampleFormat;
+    const PLANAR: SampleFormat;
+
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+impl Primitive for u8 {
+    const PACKED: SampleFormat = SampleFormat::U8;
+    const PLANAR: SampleFormat = SampleFormat::U8P;
+
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        (self as f64 - 128.0) / 128.0
+    }
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        (value * 128.0 + 128.0).round().clamp(0.0, u8::MAX as f64) as u8
+    }
+}
+
+impl Primitive for i16 {
+    const PACKED: SampleFormat = SampleFormat::S16;
+    const PLANAR: SampleFormat = SampleFormat::S16P;
+
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self as f64 / 32768.0
+    }
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        (value * 32768.0).round().clamp(i16::MIN as f64, i16::MAX as f64) as i16
+    }
+}
+
+impl Primitive for i32 {
+    const PACKED: SampleFormat = SampleFormat::S32;
+    const PLANAR: SampleFormat = SampleFormat::S32P;
+
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self as f64 / 2147483648.0
+    }
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        (value * 2147483648.0).round().clamp(i32::MIN as f64, i32::MAX as f64) as i32
+    }
+}
+
+impl Primitive for i64 {
+    const PACKED: SampleFormat = SampleFormat::S64;
+    const PLANAR: SampleFormat = SampleFormat::S64P;
+
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self as f64 / 9223372036854775808.0
+    }
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        // 超出范围时 as 会饱和截断
+        (value * 9223372036854775808.0).round() as i64
     }
 }
 
+impl Primitive for f32 {
+    const PACKED: SampleFormat = SampleFormat::FLT;
+    const PLANAR: SampleFormat = SampleFormat::FLTP;
 
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl Primitive for f64 {
+    const PACKED: SampleFormat = SampleFormat::DBL;
+    const PLANAR: SampleFormat = SampleFormat::DBLP;
+
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
@@ -4,11 +4,14 @@ pub use self::audio::Audio;
 
 use crate::ffi;
 use crate::util::dict::DictRef;
+use crate::util::rational::Rational;
 
 pub struct Frame {
     ptr: *mut ffi::AVFrame,
 }
 
+// 帧独占 AVFrame，数据缓冲区的引用计数是原子的
+unsafe impl Send for Frame {}
 
 impl Frame {
     #[inline(always)]
@@ -61,6 +64,26 @@ impl Frame {
         }
     }
 
+    #[inline]
+    pub fn set_pts(&mut self, value: Option<i64>) {
+        unsafe {
+            (*self.as_mut_ptr()).pts = value.unwrap_or(ffi::AV_NOPTS_VALUE);
+        }
+    }
+
+    /// pts 和 duration 的时间基
+    #[inline]
+    pub fn time_base(&self) -> Rational {
+        unsafe { Rational::from((*self.as_ptr()).time_base) }
+    }
+
+    #[inline]
+    pub fn set_time_base<R: Into<Rational>>(&mut self, value: R) {
+        unsafe {
+            (*self.as_mut_ptr()).time_base = value.into().into();
+        }
+    }
+
     #[inline]
     pub fn metadata(&self) -> DictRef {
         unsafe {
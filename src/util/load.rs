@@ -0,0 +1,252 @@
+use std::io::{Read, Seek};
+use std::slice;
+use std::time::Duration;
+
+use crate::codec::codec_id::CodecId;
+use crate::ffi;
+use crate::format::context::input::InputContext;
+use crate::format::input::{open, open_reader};
+use crate::software::resampling;
+use crate::util::channel_layout::ChannelLayout;
+use crate::util::error::{Error, Result};
+use crate::util::frame::audio::Primitive;
+use crate::util::frame::Audio;
+use crate::util::media;
+use crate::util::rational::{rescale, Rational};
+use crate::util::samplefmt::SampleFormat;
+
+/// 加载音频的参数，采样率和声道数为空时保持源文件的值
+#[derive(Copy, Clone)]
+pub struct LoadOptions {
+    pub rate: Option<i32>,
+    pub channels: Option<i32>,
+    /// 输出采样格式，默认 FLT
+    pub format: SampleFormat,
+    /// 起始时间，默认从头开始
+    pub start: Option<Duration>,
+    /// 截取时长，默认到结尾
+    pub duration: Option<Duration>,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        LoadOptions { rate: None, channels: None, format: SampleFormat::FLT, start: None, duration: None }
+    }
+}
+
+/// 加载结果：转换后的全部采样放在一个音频帧中，同时保留源文件的参数
+pub struct LoadedAudio {
+    pub frame: Audio,
+    pub codec: CodecId,
+    pub source_rate: i32,
+    pub source_channels: i32,
+    pub source_format: SampleFormat,
+}
+
+impl LoadedAudio {
+    pub fn rate(&self) -> i32 {
+        self.frame.sample_rate()
+    }
+
+    pub fn channels(&self) -> i32 {
+        self.frame.channels()
+    }
+
+    /// 每个声道的采样数
+    pub fn samples(&self) -> i32 {
+        self.frame.samples()
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.samples() as f64 / self.rate() as f64)
+    }
+
+    /// 交织排列的采样
+    pub fn to_interleaved<T: Primitive>(&self) -> Vec<T> {
+        self.frame.to_interleaved()
+    }
+}
+
+/// 读取音频文件中最合适的一路音频流，按 options 重采样并截取
+pub fn load_audio(filename: &str, options: LoadOptions) -> Result<LoadedAudio> {
+    load(open(filename)?, options)
+}
+
+/// 从读取器加载音频
+pub fn load_audio_reader<R: Read + Seek + Send + 'static>(reader: R, options: LoadOptions) -> Result<LoadedAudio> {
+    load(open_reader(reader)?, options)
+}
+
+fn load(mut input: InputContext, options: LoadOptions) -> Result<LoadedAudio> {
+    if options.format == SampleFormat::NONE
+        || options.rate.map_or(false, |rate| rate <= 0)
+        || options.channels.map_or(false, |channels| channels <= 0)
+    {
+        return Err(Error::InvalidArgument);
+    }
+
+    let (index, time_base, start_time) = {
+        let stream = input.streams().best(media::Type::Audio).ok_or(Error::StreamNotFound)?;
+        let start_time = match stream.start_time() {
+            ffi::AV_NOPTS_VALUE => 0,
+            start_time => start_time,
+        };
+        (stream.index(), stream.time_base(), start_time)
+    };
+
+    // 先粗略定位到起始时间之前，剩余部分在解码后按采样截掉；不可随机访问的输入无法定位，从头解码
+    let start = options.start.unwrap_or_default();
+    if !start.is_zero() {
+        let ts = start_time + rescale(start.as_micros() as i64, (1, 1_000_000), time_base);
+        if let Err(e) = input.seek_stream(index as i32, ts, ..=ts) {
+            if is_seekable(&input) {
+                return Err(e);
+            }
+        }
+    }
+
+    let mut frames = input.decode_audio(index)?;
+    let codec = frames.decoder().id();
+    let source_format = frames.decoder().format();
+    let source_rate = frames.decoder().rate();
+    let source_channels = frames.decoder().channels();
+
+    let rate = options.rate.unwrap_or(source_rate);
+    let mut collector = Collector::new(options.format, rate, &options);
+    let mut resampler: Option<resampling::Context> = None;
+    // 当前重采样器的输入参数
+    let mut source: Option<(SampleFormat, ChannelLayout, i32)> = None;
+
+    for frame in frames.by_ref() {
+        let frame = frame?;
+        let params = (frame.format(), frame.channel_layout().or_default_order(), frame.sample_rate());
+        if source.as_ref() != Some(&params) {
+            let layout = match resampler.as_mut() {
+                // 流的参数中途变化：排空原来的重采样器，按新的输入参数重建，输出参数保持不变
+                Some(previous) => {
+                    if let Some(rest) = previous.flush()? {
+                        if !collector.push(&rest) {
+                            break;
+                        }
+                    }
+                    previous.output_channel_layout()
+                }
+                None => {
+                    // 第一帧的位置决定输出采样的起点
+                    if let Some(pts) = frame.pts() {
+                        collector.position = rescale(pts - start_time, time_base, (1, rate));
+                    }
+
+                    match options.channels {
+                        Some(channels) if channels != params.1.nb_channels() => ChannelLayout::default(channels),
+                        _ => params.1.clone(),
+                    }
+                }
+            };
+
+            resampler = Some(resampling::Context::get(params.0, params.1.clone(), params.2, options.format, layout, rate)?);
+            source = Some(params);
+        }
+
+        let converted = resampler.as_mut().unwrap().convert(&frame)?;
+        if !collector.push(&converted) {
+            break;
+        }
+    }
+
+    // 一帧都没有解出来
+    let mut resampler = resampler.ok_or(Error::Eof)?;
+    if !collector.is_full() {
+        if let Some(rest) = resampler.flush()? {
+            collector.push(&rest);
+        }
+    }
+
+    Ok(LoadedAudio {
+        frame: collector.finish(resampler.output_channel_layout())?,
+        codec,
+        source_rate,
+        source_channels,
+        source_format,
+    })
+}
+
+fn is_seekable(input: &InputContext) -> bool {
+    unsafe {
+        let pb = (*input.as_ptr()).pb;
+        !pb.is_null() && (*pb).seekable != 0
+    }
+}
+
+/// 按输出采样位置截取并拼接转换后的帧
+struct Collector {
+    format: SampleFormat,
+    rate: i32,
+    /// 下一个输入采样在输出中的位置
+    position: i64,
+    begin: i64,
+    end: Option<i64>,
+    planes: Vec<Vec<u8>>,
+    samples: i64,
+}
+
+impl Collector {
+    fn new(format: SampleFormat, rate: i32, options: &LoadOptions) -> Self {
+        let to_samples = |d: Duration| rescale(d.as_micros() as i64, (1, 1_000_000), (1, rate));
+        let begin = options.start.map_or(0, to_samples);
+        let end = options.duration.map(|d| begin + to_samples(d));
+        Collector { format, rate, position: 0, begin, end, planes: Vec::new(), samples: 0 }
+    }
+
+    fn is_full(&self) -> bool {
+        self.end.map_or(false, |end| self.position >= end)
+    }
+
+    /// 追加一帧中落在截取范围内的采样，已经取满时返回 false
+    fn push(&mut self, frame: &Audio) -> bool {
+        let samples = frame.samples() as i64;
+        let from = (self.begin - self.position).clamp(0, samples);
+        let to = self.end.map_or(samples, |end| (end - self.position).clamp(0, samples));
+        self.position += samples;
+
+        if to > from {
+            let width = if frame.is_packed() {
+                self.format.bytes() * frame.channels() as usize
+            } else {
+                self.format.bytes()
+            };
+
+            self.planes.resize(frame.planes(), Vec::new());
+            for (i, plane) in self.planes.iter_mut().enumerate() {
+                let data = plane_bytes(frame, i, samples as usize * width);
+                plane.extend_from_slice(&data[from as usize * width..to as usize * width]);
+            }
+            self.samples += to - from;
+        }
+
+        !self.is_full()
+    }
+
+    fn finish(self, layout: ChannelLayout) -> Result<Audio> {
+        let mut frame = Audio::new(self.format, self.samples as i32, layout);
+        if self.samples > 0 && frame.is_empty() {
+            return Err(Error::NoMemory);
+        }
+
+        for (i, plane) in self.planes.iter().enumerate() {
+            unsafe {
+                let dst = *(*frame.as_mut_ptr()).extended_data.add(i);
+                slice::from_raw_parts_mut(dst, plane.len()).copy_from_slice(plane);
+            }
+        }
+
+        frame.set_sample_rate(self.rate);
+        frame.set_pts(Some(0));
+        frame.set_time_base(Rational::new(1, self.rate));
+        Ok(frame)
+    }
+}
+
+fn plane_bytes(frame: &Audio, index: usize, len: usize) -> &[u8] {
+    unsafe { slice::from_raw_parts(*(*frame.as_ptr()).extended_data.add(index), len) }
+}
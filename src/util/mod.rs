@@ -4,4 +4,11 @@ pub mod samplefmt;
 pub mod channel_layout;
 pub mod dict;
 pub mod rational;
-pub mod discard;
\ No newline at end of file
+pub mod timestamp;
+pub mod discard;
+pub mod error;
+pub mod options;
+#[cfg(feature = "software-resampling")]
+pub mod load;
+#[cfg(feature = "software-resampling")]
+pub use self::load::{load_audio, load_audio_reader, LoadOptions, LoadedAudio};
@@ -0,0 +1,244 @@
+use std::ffi::CStr;
+use std::ptr;
+use std::str::from_utf8_unchecked;
+
+use libc::{c_char, c_int, c_void};
+
+use crate::ffi;
+use crate::util::channel_layout::ChannelLayout;
+use crate::util::error::{c_string, Error, Result};
+use crate::util::rational::Rational;
+
+/// 同时查找子对象中的选项，例如编解码器上下文中编码器的私有选项
+const SEARCH_FLAGS: c_int = ffi::AV_OPT_SEARCH_CHILDREN as c_int;
+
+/// 基于 AVOption 的参数读写，适用于以 AVClass 指针开头的结构体，如编解码器、格式及滤镜上下文
+///
+/// 读写时会查找子对象，因此编码器的私有选项（如 opus 的 application）也可以直接设置
+pub trait Options {
+    /// 以 AVClass 指针开头的结构体
+    unsafe fn as_options_ptr(&self) -> *mut c_void;
+
+    /// 以字符串设置选项，由 FFmpeg 按选项类型解析
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let name = c_string(name)?;
+        let value = c_string(value)?;
+        unsafe { check(ffi::av_opt_set(self.as_options_ptr(), name.as_ptr(), value.as_ptr(), SEARCH_FLAGS)) }
+    }
+
+    /// 以字符串形式读取选项的当前值
+    fn get(&self, name: &str) -> Result<String> {
+        let name = c_string(name)?;
+        unsafe {
+            let mut value: *mut u8 = ptr::null_mut();
+            check(ffi::av_opt_get(self.as_options_ptr(), name.as_ptr(), SEARCH_FLAGS, &mut value))?;
+            if value.is_null() {
+                return Ok(String::new());
+            }
+
+            let result = CStr::from_ptr(value as *const c_char).to_string_lossy().into_owned();
+            ffi::av_free(value as *mut c_void);
+            Ok(result)
+        }
+    }
+
+    fn set_int(&mut self, name: &str, value: i64) -> Result<()> {
+        let name = c_string(name)?;
+        unsafe { check(ffi::av_opt_set_int(self.as_options_ptr(), name.as_ptr(), value, SEARCH_FLAGS)) }
+    }
+
+    fn set_rational<R: Into<Rational>>(&mut self, name: &str, value: R) -> Result<()>
+        where Self: Sized
+    {
+        let name = c_string(name)?;
+        unsafe { check(ffi::av_opt_set_q(self.as_options_ptr(), name.as_ptr(), value.into().into(), SEARCH_FLAGS)) }
+    }
+
+    fn set_channel_layout(&mut self, name: &str, value: &ChannelLayout) -> Result<()> {
+        let name = c_string(name)?;
+        unsafe { check(ffi::av_opt_set_chlayout(self.as_options_ptr(), name.as_ptr(), value.as_ptr(), SEARCH_FLAGS)) }
+    }
+
+    /// 列出自身及子对象的全部选项
+    fn options(&self) -> Vec<OptionInfo> {
+        let mut options = Vec::new();
+        unsafe { collect(self.as_options_ptr(), &mut options) }
+        options
+    }
+}
+
+/// 选项的类型
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OptionType {
+    Flags,
+    Int,
+    Int64,
+    UInt64,
+    Double,
+    Float,
+    String,
+    Rational,
+    Binary,
+    Dict,
+    ImageSize,
+    PixelFormat,
+    SampleFormat,
+    VideoRate,
+    Duration,
+    Color,
+    Bool,
+    ChannelLayout,
+    Other,
+}
+
+impl From<ffi::AVOptionType> for OptionType {
+    fn from(value: ffi::AVOptionType) -> Self {
+        use crate::ffi::AVOptionType::*;
+
+        match value {
+            AV_OPT_TYPE_FLAGS => OptionType::Flags,
+            AV_OPT_TYPE_INT => OptionType::Int,
+            AV_OPT_TYPE_INT64 => OptionType::Int64,
+            AV_OPT_TYPE_UINT64 => OptionType::UInt64,
+            AV_OPT_TYPE_DOUBLE => OptionType::Double,
+            AV_OPT_TYPE_FLOAT => OptionType::Float,
+            AV_OPT_TYPE_STRING => OptionType::String,
+            AV_OPT_TYPE_RATIONAL => OptionType::Rational,
+            AV_OPT_TYPE_BINARY => OptionType::Binary,
+            AV_OPT_TYPE_DICT => OptionType::Dict,
+            AV_OPT_TYPE_IMAGE_SIZE => OptionType::ImageSize,
+            AV_OPT_TYPE_PIXEL_FMT => OptionType::PixelFormat,
+            AV_OPT_TYPE_SAMPLE_FMT => OptionType::SampleFormat,
+            AV_OPT_TYPE_VIDEO_RATE => OptionType::VideoRate,
+            AV_OPT_TYPE_DURATION => OptionType::Duration,
+            AV_OPT_TYPE_COLOR => OptionType::Color,
+            AV_OPT_TYPE_BOOL => OptionType::Bool,
+            AV_OPT_TYPE_CHLAYOUT => OptionType::ChannelLayout,
+            // 已废弃的 uint64 声道布局及常量等
+            #[allow(unreachable_patterns)]
+            _ => OptionType::Other,
+        }
+    }
+}
+
+/// 选项的默认值
+#[derive(Clone, Debug, PartialEq)]
+pub enum OptionValue {
+    Int(i64),
+    Double(f64),
+    String(String),
+    Rational(Rational),
+    None,
+}
+
+/// 一个选项的描述
+#[derive(Clone, Debug)]
+pub struct OptionInfo {
+    pub name: String,
+    pub help: Option<String>,
+    pub kind: OptionType,
+    pub default: OptionValue,
+    /// 数值类型的取值范围
+    pub min: f64,
+    pub max: f64,
+    /// 可用的命名取值，如 opus application 的 voip、audio、lowdelay
+    pub constants: Vec<String>,
+}
+
+unsafe fn collect(obj: *mut c_void, options: &mut Vec<OptionInfo>) {
+    let mut opt: *const ffi::AVOption = ptr::null();
+    loop {
+        opt = ffi::av_opt_next(obj, opt);
+        if opt.is_null() {
+            break;
+        }
+        if (*opt).type_ == ffi::AVOptionType::AV_OPT_TYPE_CONST {
+            continue;
+        }
+        options.push(info(obj, opt));
+    }
+
+    let mut child: *mut c_void = ptr::null_mut();
+    loop {
+        child = ffi::av_opt_child_next(obj, child);
+        if child.is_null() {
+            break;
+        }
+        collect(child, options);
+    }
+}
+
+unsafe fn info(obj: *mut c_void, opt: *const ffi::AVOption) -> OptionInfo {
+    let kind = OptionType::from((*opt).type_);
+    let default = match kind {
+        OptionType::Flags
+        | OptionType::Int
+        | OptionType::Int64
+        | OptionType::UInt64
+        | OptionType::PixelFormat
+        | OptionType::SampleFormat
+        | OptionType::Duration
+        | OptionType::Bool => OptionValue::Int((*opt).default_val.i64_),
+        OptionType::Double | OptionType::Float => OptionValue::Double((*opt).default_val.dbl),
+        OptionType::Rational => OptionValue::Rational(Rational::from((*opt).default_val.q)),
+        OptionType::String
+        | OptionType::ImageSize
+        | OptionType::VideoRate
+        | OptionType::Color
+        | OptionType::Dict
+        | OptionType::ChannelLayout => match to_string((*opt).default_val.str_) {
+            Some(value) => OptionValue::String(value),
+            None => OptionValue::None,
+        },
+        OptionType::Binary | OptionType::Other => OptionValue::None,
+    };
+
+    OptionInfo {
+        name: to_string((*opt).name).unwrap_or_default(),
+        help: to_string((*opt).help),
+        kind,
+        default,
+        min: (*opt).min,
+        max: (*opt).max,
+        constants: constants(obj, (*opt).unit),
+    }
+}
+
+/// 同一对象中与选项 unit 相同的常量
+unsafe fn constants(obj: *mut c_void, unit: *const c_char) -> Vec<String> {
+    let mut constants = Vec::new();
+    if unit.is_null() {
+        return constants;
+    }
+
+    let unit = CStr::from_ptr(unit);
+    let mut opt: *const ffi::AVOption = ptr::null();
+    loop {
+        opt = ffi::av_opt_next(obj, opt);
+        if opt.is_null() {
+            break;
+        }
+        if (*opt).type_ == ffi::AVOptionType::AV_OPT_TYPE_CONST
+            && !(*opt).unit.is_null()
+            && CStr::from_ptr((*opt).unit) == unit
+        {
+            constants.extend(to_string((*opt).name));
+        }
+    }
+    constants
+}
+
+unsafe fn to_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes()).to_string())
+    }
+}
+
+fn check(code: c_int) -> Result<()> {
+    match code {
+        e if e < 0 => Err(Error::from(e)),
+        _ => Ok(()),
+    }
+}
@@ -1,18 +1,26 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
 use libc::c_int;
 use crate::ffi;
-use anyhow::{anyhow, Result};
+use crate::util::error::{Error, Result};
 
+#[derive(Copy, Clone)]
 pub struct Rational {
     num: i32,
     den: i32,
 }
 
 impl Rational {
-    pub fn new(num: i32, den: i32) -> Self {
+    pub const fn new(num: i32, den: i32) -> Self {
         Self { num, den }
     }
 
+    /// 0/0，无法与其他值比较
+    fn is_nan(&self) -> bool {
+        self.num == 0 && self.den == 0
+    }
+
     pub fn num(&self) -> i32 {
         self.num
     }
@@ -21,6 +29,11 @@ impl Rational {
         self.den
     }
 
+    /// 倒数
+    pub fn invert(&self) -> Rational {
+        unsafe { Rational::from(ffi::av_inv_q((*self).into())) }
+    }
+
     pub fn reduce(&self) -> Result<Rational> {
         unsafe {
             let mut dst_num: c_int = 0;
@@ -30,7 +43,7 @@ impl Rational {
             if exact == 1 {
                 Ok(Rational::new(dst_num, dst_len))
             } else {
-                Err(anyhow!(""))
+                Err(Error::InvalidArgument)
             }
         }
     }
@@ -84,6 +97,109 @@ impl From<(i32, i32)> for Rational {
     }
 }
 
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Rational {}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // av_cmp_q 无法比较 0/0，将其视为彼此相等且大于其他任何值，保证全序
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => {}
+        }
+
+        unsafe {
+            match ffi::av_cmp_q((*self).into(), (*other).into()) {
+                0 => Ordering::Equal,
+                c if c < 0 => Ordering::Less,
+                _ => Ordering::Greater,
+            }
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        unsafe { Rational::from(ffi::av_add_q(self.into(), other.into())) }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        unsafe { Rational::from(ffi::av_sub_q(self.into(), other.into())) }
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        unsafe { Rational::from(ffi::av_mul_q(self.into(), other.into())) }
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        unsafe { Rational::from(ffi::av_div_q(self.into(), other.into())) }
+    }
+}
+
+/// 时间戳换算时的取整方式
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    /// 向 0 取整
+    Zero,
+    /// 远离 0 取整
+    Infinity,
+    /// 向负无穷取整
+    Down,
+    /// 向正无穷取整
+    Up,
+    /// 四舍五入，0.5 远离 0
+    NearInfinity,
+}
+
+impl From<Rounding> for ffi::AVRounding {
+    fn from(value: Rounding) -> ffi::AVRounding {
+        match value {
+            Rounding::Zero => ffi::AVRounding::AV_ROUND_ZERO,
+            Rounding::Infinity => ffi::AVRounding::AV_ROUND_INF,
+            Rounding::Down => ffi::AVRounding::AV_ROUND_DOWN,
+            Rounding::Up => ffi::AVRounding::AV_ROUND_UP,
+            Rounding::NearInfinity => ffi::AVRounding::AV_ROUND_NEAR_INF,
+        }
+    }
+}
+
+/// 把时间基为 from 的时间戳换算到时间基 to，四舍五入
+pub fn rescale<F: Into<Rational>, T: Into<Rational>>(ts: i64, from: F, to: T) -> i64 {
+    rescale_rnd(ts, from, to, Rounding::NearInfinity)
+}
+
+/// 按指定的取整方式换算时间戳
+pub fn rescale_rnd<F: Into<Rational>, T: Into<Rational>>(ts: i64, from: F, to: T, rounding: Rounding) -> i64 {
+    unsafe { ffi::av_rescale_q_rnd(ts, from.into().into(), to.into().into(), rounding.into()) }
+}
+
 impl fmt::Debug for Rational {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         f.write_str(&format!(
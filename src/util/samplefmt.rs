@@ -78,9 +78,10 @@ impl From<ffi::AVSampleFormat> for SampleFormat {
 impl From<&'static str> for SampleFormat {
     #[inline]
     fn from(value: &'static str) -> Self {
-        unsafe {
-            let value = CString::new(value).unwrap();
-            SampleFormat::from(ffi::av_get_sample_fmt(value.as_ptr()))
+        // 含有 NUL 的名称不会是合法的采样格式
+        match CString::new(value) {
+            Ok(value) => unsafe { SampleFormat::from(ffi::av_get_sample_fmt(value.as_ptr())) },
+            Err(_) => SampleFormat::NONE,
         }
     }
 }
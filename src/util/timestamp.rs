@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use crate::ffi;
+use crate::util::rational::{rescale, Rational};
+
+/// 纳秒时间基，用于和 Duration 互相转换
+const NANOSECONDS: Rational = Rational::new(1, 1_000_000_000);
+
+/// 带时间基的时间戳，如流的 duration、包和帧的 pts
+#[derive(Copy, Clone, Debug)]
+pub struct Timestamp {
+    pub value: i64,
+    pub time_base: Rational,
+}
+
+impl Timestamp {
+    pub fn new<R: Into<Rational>>(value: i64, time_base: R) -> Self {
+        Timestamp { value, time_base: time_base.into() }
+    }
+
+    /// 由 Duration 按指定时间基构造，四舍五入
+    pub fn from_duration<R: Into<Rational>>(duration: Duration, time_base: R) -> Self {
+        let nanos = i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX);
+        let time_base = time_base.into();
+        Timestamp { value: rescale(nanos, NANOSECONDS, time_base), time_base }
+    }
+
+    /// 以秒为单位的浮点值
+    pub fn as_secs_f64(&self) -> f64 {
+        self.value as f64 * f64::from(self.time_base)
+    }
+
+    /// 转换为 Duration，负数时返回 None
+    pub fn as_duration(&self) -> Option<Duration> {
+        match rescale(self.value, self.time_base, NANOSECONDS) {
+            nanos if nanos < 0 => None,
+            nanos => Some(Duration::from_nanos(nanos as u64)),
+        }
+    }
+
+    /// 换算到另一个时间基
+    pub fn rescale<R: Into<Rational>>(&self, time_base: R) -> Timestamp {
+        let time_base = time_base.into();
+        Timestamp { value: rescale(self.value, self.time_base, time_base), time_base }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    /// 不同时间基之间按实际时间比较
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe {
+            ffi::av_compare_ts(self.value, self.time_base.into(), other.value, other.time_base.into()).cmp(&0)
+        }
+    }
+}
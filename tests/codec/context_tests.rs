@@ -1,16 +1,22 @@
-use ffmpeg_di::codec::codec_id::avcodec_get_name;
+use std::io::Cursor;
+
+use ffmpeg_di::codec::codec_id::{avcodec_get_name, CodecId};
 use ffmpeg_di::codec::context::Context;
-use ffmpeg_di::format::input::{find_input_format, open_with_format};
+use ffmpeg_di::format::input::open_raw_pcm_reader;
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::samplefmt::SampleFormat;
 use ffmpeg_di::util::{frame, media};
 
 #[test]
 pub fn test_generated_codec_context() {
-    let short_name = "s16le";
-    let f = find_input_format(short_name).expect(&format!("no such input format: {}", short_name));
-    let mut c = open_with_format("tests/assets/16bit_c1.pcm", f).unwrap();
+    // 1 秒 16kHz 单声道 s16le
+    let pcm: Vec<u8> = (0..16000i16).flat_map(|i| (i % 100).to_le_bytes()).collect();
+    let mut c = open_raw_pcm_reader(Cursor::new(pcm), SampleFormat::S16, 16000, ChannelLayout::default(1)).unwrap();
     let s = c.streams().best(media::Type::Audio).unwrap();
+    let index = s.index() as i32;
     let p = s.parameters();
     let i = p.codec_id();
+    assert_eq!(i, CodecId::PCM_S16LE);
     let name = avcodec_get_name(i).unwrap();
     println!("{:?}", name);
 
@@ -19,19 +25,22 @@ pub fn test_generated_codec_context() {
 
     //获取decoder
     let mut audio = context.decoder().audio().unwrap();
-    audio.set_rate(16000);
     let rate = audio.rate();
     let channels = audio.channels();
+    assert_eq!(rate, 16000);
+    assert_eq!(channels, 1);
 
-    for (_smp, pkt) in c.packets() {
-        if pkt.stream() == 0 {
+    let mut samples = 0;
+    let mut frame = frame::Audio::empty();
+    for item in c.packets() {
+        let (_, pkt) = item.unwrap();
+        if pkt.stream_index() == index {
             audio.send_packet(&pkt).unwrap();
-
-            let mut frame = frame::Frame::empty();
-            audio.receive_packet(&mut frame).unwrap();
+            while audio.receive_frame(&mut frame).is_ok() {
+                samples += frame.samples();
+            }
         }
-
     }
+    assert_eq!(samples, 16000);
     println!("{} {}", rate, channels);
-
-}
\ No newline at end of file
+}
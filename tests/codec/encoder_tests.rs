@@ -0,0 +1,53 @@
+use ffmpeg_di::codec::codec_id::CodecId;
+use ffmpeg_di::codec::context::{find_encoder, Context};
+use ffmpeg_di::format::packet::Packet;
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::dict::Dictionary;
+use ffmpeg_di::util::frame;
+use ffmpeg_di::util::samplefmt::SampleFormat;
+use ffmpeg_di::Error;
+
+#[test]
+pub fn test_audio_encoder_setters() {
+    let codec = find_encoder(CodecId::PCM_S16LE).expect("pcm_s16le encoder not found");
+    assert!(codec.is_encoder());
+
+    let mut audio = Context::new_with_codec(&codec).encoder().audio().unwrap();
+    audio.set_rate(16000);
+    audio.set_format(SampleFormat::S16);
+    audio.set_bit_rate(256000);
+
+    assert_eq!(audio.rate(), 16000);
+    assert!(audio.format() == SampleFormat::S16);
+    assert_eq!(audio.bit_rate(), 256000);
+}
+
+#[test]
+pub fn test_audio_encode() {
+    let codec = find_encoder(CodecId::PCM_S16LE).unwrap();
+    let mut audio = Context::new_with_codec(&codec).encoder().audio().unwrap();
+    audio.set_rate(16000);
+    audio.set_format(SampleFormat::S16);
+    audio.set_channel_layout(ChannelLayout::default(1));
+    audio.set_time_base((1, 16000));
+    let mut encoder = audio.open_as_with(codec, Dictionary::new()).unwrap();
+
+    let samples: Vec<i16> = (0..1600).map(|i| (i * 20) as i16).collect();
+    let frame = frame::Audio::from_interleaved(&samples, ChannelLayout::default(1), 16000).unwrap();
+    encoder.send_frame(&frame).unwrap();
+    encoder.send_eof().unwrap();
+
+    let mut sizes = Vec::new();
+    let mut packet = Packet::empty();
+    loop {
+        match encoder.receive_packet(&mut packet) {
+            Ok(..) => sizes.push(packet.size()),
+            Err(Error::Eof) => break,
+            Err(e) => panic!("{:?}", e),
+        }
+    }
+
+    // pcm 每个采样 2 字节，一帧编码为一个包
+    assert_eq!(sizes, vec![3200]);
+    assert!(matches!(encoder.send_frame(&frame), Err(Error::Eof)));
+}
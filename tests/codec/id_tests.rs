@@ -1,9 +1,36 @@
 // tests/codec/id_tests.rs
 
 use ffmpeg_di::codec::codec_id::{avcodec_get_name, CodecId};
+use ffmpeg_di::ffi::AVCodecID;
+use ffmpeg_di::util::media;
 
 #[test]
 pub fn test_avcodec_get_name() {
     let name = avcodec_get_name(CodecId::PCM_S16BE);
     println!("{:?}", name.unwrap());
-}
\ No newline at end of file
+}
+#[test]
+pub fn test_codec_id_round_trip() {
+    for id in [CodecId::PCM_MULAW, CodecId::PCM_ALAW, CodecId::G729, CodecId::AMR_NB, CodecId::AMR_WB, CodecId::OPUS, CodecId::MP3] {
+        let raw: AVCodecID = id.into();
+        assert_eq!(CodecId::from(raw), id);
+    }
+
+    // 别名与原名是同一个取值
+    let raw: AVCodecID = CodecId::H265.into();
+    assert_eq!(CodecId::from(raw), CodecId::HEVC);
+    assert_eq!(CodecId::from(AVCodecID::AV_CODEC_ID_IFF_ILBM), CodecId::IFF_BYTERUN1);
+
+    assert_eq!(CodecId::G729.name(), "g729");
+    assert_eq!(CodecId::AAC.medium(), media::Type::Audio);
+}
+
+#[test]
+#[cfg(feature = "ffmpeg_6_0")]
+pub fn test_codec_id_ffmpeg_6_0() {
+    for id in [CodecId::BONK, CodecId::APAC, CodecId::RKA, CodecId::WAVARC, CodecId::ADPCM_XMD] {
+        let raw: AVCodecID = id.into();
+        assert_eq!(CodecId::from(raw), id);
+        assert_eq!(id.medium(), media::Type::Audio);
+    }
+}
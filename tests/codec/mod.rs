@@ -1,2 +1,5 @@
 mod id_tests;
-mod context_tests;
\ No newline at end of file
+mod context_tests;
+mod encoder_tests;
+mod parameters_tests;
+mod registry_tests;
@@ -0,0 +1,60 @@
+use ffmpeg_di::codec::codec_id::CodecId;
+use ffmpeg_di::codec::codec_par::Parameters;
+use ffmpeg_di::codec::context::Context;
+use ffmpeg_di::format::input::open;
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::media;
+use ffmpeg_di::util::samplefmt::SampleFormat;
+
+#[test]
+fn test_stream_parameters() {
+    let c = open("tests/assets/snd_u8.wav").unwrap();
+    let p = c.streams().best(media::Type::Audio).unwrap().parameters();
+
+    assert!(p.medium() == media::Type::Audio);
+    assert!(p.codec_id() == CodecId::PCM_U8);
+    assert_eq!(p.codec_tag(), 1);
+    assert_eq!(p.rate(), 16000);
+    assert_eq!(p.channels(), 1);
+    assert!(p.format() == SampleFormat::U8);
+    assert_eq!(p.bits_per_coded_sample(), 8);
+    assert_eq!(p.block_align(), 1);
+    assert_eq!(p.bit_rate(), 128000);
+    assert!(p.extradata().is_none());
+}
+
+#[test]
+fn test_set_parameters() {
+    let mut p = Parameters::new();
+    p.set_medium(media::Type::Audio);
+    p.set_codec_id(CodecId::AAC);
+    p.set_rate(48000);
+    p.set_channel_layout(ChannelLayout::default(2));
+    p.set_format(SampleFormat::FLTP);
+    p.set_frame_size(1024);
+    p.set_initial_padding(1024);
+    p.set_extradata(&[0x11, 0x90]).unwrap();
+
+    let context = Context::parameters_to_context(p.clone()).unwrap();
+    let copied = Parameters::from_context(&context).unwrap();
+    assert!(copied.codec_id() == CodecId::AAC);
+    assert_eq!(copied.rate(), 48000);
+    assert!(copied.channel_layout() == ChannelLayout::default(2));
+    assert!(copied.format() == SampleFormat::FLTP);
+    assert_eq!(copied.initial_padding(), 1024);
+    assert_eq!(copied.extradata(), Some(&[0x11, 0x90][..]));
+
+    p.set_extradata(&[]).unwrap();
+    assert!(p.extradata().is_none());
+}
+
+#[test]
+fn test_video_parameters_format() {
+    let mut p = Parameters::new();
+    p.set_medium(media::Type::Video);
+    // 视频参数中 format 为像素格式，可能超出采样格式的范围
+    unsafe {
+        (*p.as_mut_ptr()).format = 100;
+    }
+    assert!(p.format() == SampleFormat::NONE);
+}
@@ -0,0 +1,28 @@
+use ffmpeg_di::codec::codec_id::CodecId;
+use ffmpeg_di::codec::{decoders, encoders, find_decoder_by_name, find_encoder_by_name};
+
+#[test]
+pub fn test_codec_iterate() {
+    assert!(decoders().all(|c| c.is_decoder()));
+    assert!(encoders().all(|c| c.is_encoder()));
+    assert!(decoders().any(|c| c.id() == CodecId::PCM_ALAW));
+}
+
+#[test]
+pub fn test_find_by_name() {
+    let decoder = find_decoder_by_name("pcm_alaw").unwrap();
+    assert_eq!(decoder.id(), CodecId::PCM_ALAW);
+    assert!(find_encoder_by_name("pcm_s16le").unwrap().is_encoder());
+    assert!(find_decoder_by_name("no_such_codec").is_none());
+}
+
+#[test]
+pub fn test_codec_descriptor() {
+    let descriptor = find_decoder_by_name("pcm_s16le").unwrap().descriptor().unwrap();
+    assert_eq!(descriptor.name(), "pcm_s16le");
+    assert!(descriptor.is_lossless());
+    assert!(descriptor.is_intra_only());
+
+    let descriptor = find_decoder_by_name("pcm_alaw").unwrap().descriptor().unwrap();
+    assert!(descriptor.is_lossy());
+}
@@ -0,0 +1,64 @@
+use ffmpeg_di::codec::context::Context;
+use ffmpeg_di::filter::Graph;
+use ffmpeg_di::format::input::open;
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::rational::Rational;
+use ffmpeg_di::util::samplefmt::SampleFormat;
+use ffmpeg_di::util::{frame, media};
+use ffmpeg_di::Error;
+
+#[test]
+pub fn test_filter_graph() {
+    let mut c = open("tests/assets/snd_u8.wav").unwrap();
+    let stream = c.streams().best(media::Type::Audio).unwrap();
+    let index = stream.index() as i32;
+    let mut decoder = Context::parameters_to_context(stream.parameters()).unwrap().decoder().audio().unwrap();
+
+    let mut graph = Graph::new();
+    graph.add_abuffer(&decoder).unwrap();
+    graph.add_abuffersink().unwrap();
+    graph.parse("highpass=f=80,aresample=16000,aformat=sample_fmts=s16:channel_layouts=mono").unwrap();
+    graph.validate().unwrap();
+
+    let mut samples = 0;
+    let mut decoded = frame::Audio::empty();
+    let mut filtered = frame::Audio::empty();
+    for item in c.packets() {
+        let (_, packet) = item.unwrap();
+        if packet.stream_index() != index {
+            continue;
+        }
+        decoder.send_packet(&packet).unwrap();
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            graph.push(&decoded).unwrap();
+            while graph.pull(&mut filtered).is_ok() {
+                assert_eq!(filtered.sample_rate(), 16000);
+                samples += filtered.samples();
+            }
+        }
+    }
+
+    graph.flush().unwrap();
+    loop {
+        match graph.pull(&mut filtered) {
+            Ok(..) => samples += filtered.samples(),
+            Err(Error::Eof) => break,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    assert!(samples > 0);
+}
+
+#[test]
+pub fn test_filter_graph_invalid() {
+    let mut graph = Graph::default();
+    let mut frame = frame::Audio::empty();
+    assert!(matches!(graph.push(&frame), Err(Error::InvalidArgument)));
+    assert!(matches!(graph.flush(), Err(Error::InvalidArgument)));
+    assert!(matches!(graph.pull(&mut frame), Err(Error::InvalidArgument)));
+
+    graph.add_abuffer_with(SampleFormat::S16, ChannelLayout::default(1), 16000, Rational::new(1, 16000)).unwrap();
+    graph.add_abuffersink().unwrap();
+    assert!(matches!(graph.parse("volume=1\0"), Err(Error::InvalidArgument)));
+}
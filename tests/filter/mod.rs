@@ -0,0 +1 @@
+mod graph_tests;
@@ -0,0 +1,58 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use ffmpeg_di::format::async_input::AsyncInput;
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// 读到 limit 字节后 panic 的数据源
+struct PanicAfter {
+    data: Vec<u8>,
+    pos: usize,
+    limit: usize,
+}
+
+impl AsyncRead for PanicAfter {
+    fn poll_read(mut self: Pin<&mut Self>, _cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        if self.pos >= self.limit {
+            panic!("source failed");
+        }
+
+        let end = (self.pos + buf.remaining()).min(self.limit);
+        buf.put_slice(&self.data[self.pos..end]);
+        self.pos = end;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn test_async_input() {
+    let file = tokio::fs::File::open("tests/assets/snd_u8.wav").await.unwrap();
+    let mut input = AsyncInput::with_capacity(file, None, 2);
+
+    let mut samples = 0;
+    while let Some(frame) = input.next_frame().await {
+        samples += frame.unwrap().samples();
+    }
+    assert_eq!(samples, 3607479);
+}
+
+#[tokio::test]
+async fn test_async_input_invalid() {
+    let mut input = AsyncInput::new(&b"not a media file"[..]);
+    assert!(input.next_frame().await.unwrap().is_err());
+    assert!(input.next_frame().await.is_none());
+}
+
+#[tokio::test]
+async fn test_async_input_aborted() {
+    let data = std::fs::read("tests/assets/snd_u8.wav").unwrap();
+    let mut input = AsyncInput::new(PanicAfter { data, pos: 0, limit: 200_000 });
+
+    // 读取任务 panic 后以错误结束，而不是当作正常读完
+    let mut last = None;
+    while let Some(frame) = input.next_frame().await {
+        last = Some(frame);
+    }
+    assert!(last.unwrap().is_err());
+}
@@ -1,5 +1,13 @@
-use ffmpeg_di::format::input::{find_input_format, open_with_format};
+use std::io::{Cursor, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use ffmpeg_di::codec::context::Context;
+use ffmpeg_di::format::input::{demuxers, find_input_format, open, open_reader, open_unseekable_reader, open_with, open_with_format, probe, OpenOptions};
+use ffmpeg_di::util::dict::Dictionary;
+use ffmpeg_di::Error;
+use ffmpeg_di::util::frame;
 use ffmpeg_di::util::media::Type;
+use ffmpeg_di::util::rational::rescale;
 
 #[test]
 fn test_av_find_input_format() {
@@ -39,4 +47,143 @@ fn test_best_stream() {
         }
         Err(_) => {}
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_open_reader() {
+    let data = std::fs::read("tests/assets/snd_u8.wav").unwrap();
+    let c = open_reader(Cursor::new(data)).unwrap();
+
+    assert_eq!(c.format().name(), "wav");
+    assert!(c.streams().best(Type::Audio).is_some());
+}
+
+#[test]
+fn test_open_unseekable_reader() {
+    let data = std::fs::read("tests/assets/snd_u8.wav").unwrap();
+    let mut c = open_unseekable_reader(Cursor::new(data)).unwrap();
+
+    assert!(c.packets().count() > 0);
+}
+
+struct PanicReader;
+
+impl Read for PanicReader {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        panic!("read failed")
+    }
+}
+
+#[test]
+fn test_open_panicking_reader() {
+    // 读取器 panic 时打开失败，而不是终止进程
+    assert!(open_unseekable_reader(PanicReader).is_err());
+}
+
+#[test]
+fn test_seek() {
+    let mut c = open("tests/assets/snd_u8.wav").unwrap();
+    let stream = c.streams().best(Type::Audio).unwrap();
+    let index = stream.index() as i32;
+    let time_base = stream.time_base();
+    let mut decoder = Context::parameters_to_context(stream.parameters()).unwrap().decoder().audio().unwrap();
+    let mut decoded = frame::Audio::empty();
+
+    // 先解码开头的几个包
+    for item in c.packets().take(4) {
+        let (_, packet) = item.unwrap();
+        decoder.send_packet(&packet).unwrap();
+        while decoder.receive_frame(&mut decoded).is_ok() {}
+    }
+
+    // 定位到第 100 秒，之后第一帧落在目标之前且离目标不远
+    let target = rescale(100, (1, 1), time_base);
+    c.seek_stream(index, target, ..=target).unwrap();
+    decoder.flush();
+
+    let pts = loop {
+        let (_, packet) = c.packets().next().unwrap().unwrap();
+        if packet.stream_index() != index {
+            continue;
+        }
+        decoder.send_packet(&packet).unwrap();
+        if decoder.receive_frame(&mut decoded).is_ok() {
+            break decoded.pts().unwrap();
+        }
+    };
+    assert!(pts <= target);
+    assert!(pts > rescale(99, (1, 1), time_base));
+}
+
+#[test]
+fn test_probe() {
+    let data = std::fs::read("tests/assets/snd_u8.wav").unwrap();
+    let (format, score) = probe(&data[..1024]).unwrap();
+    assert_eq!(format.name(), "wav");
+    assert!(score > 0);
+
+    assert!(probe(&[0u8; 16]).is_none());
+    assert!(demuxers().any(|f| f.name() == "wav"));
+}
+
+#[test]
+fn test_open_with_options() {
+    let options: Dictionary = [("no_such_option", "1")].into_iter().collect();
+    let (c, unused) = open_with("tests/assets/snd_u8.wav", find_input_format("wav"), options).unwrap();
+    assert_eq!(c.format().name(), "wav");
+    assert!(unused.iter().any(|(k, v)| k == "no_such_option" && v == "1"));
+}
+
+#[test]
+fn test_open_interrupted() {
+    let flag = Arc::new(AtomicBool::new(true));
+    let res = OpenOptions::new()
+        .probe_size(4096)
+        .interrupt_flag(flag)
+        .open("tests/assets/snd_u8.wav");
+    assert!(matches!(res, Err(Error::Exit)));
+}
+
+#[test]
+fn test_packets_interrupted() {
+    let flag = Arc::new(AtomicBool::new(false));
+    let (mut c, _) = OpenOptions::new()
+        .interrupt_flag(flag.clone())
+        .open("tests/assets/snd_u8.wav")
+        .unwrap();
+    flag.store(true, Ordering::Relaxed);
+
+    // 已缓冲的包仍可读出，之后以 Error::Exit 结束，不会一直重试
+    let last = c.packets().last().unwrap();
+    assert!(matches!(last, Err(Error::Exit)));
+}
+
+#[test]
+fn test_decode_audio() {
+    let mut c = open("tests/assets/snd_u8.wav").unwrap();
+    let index = c.streams().best(Type::Audio).unwrap().index();
+
+    let mut samples = 0;
+    for frame in c.decode_audio(index).unwrap() {
+        samples += frame.unwrap().samples();
+    }
+    assert!(samples > 0);
+
+    assert!(matches!(c.decode_audio(99), Err(Error::StreamNotFound)));
+}
+
+#[test]
+fn test_decode_on_other_thread() {
+    let data = std::fs::read("tests/assets/snd_u8.wav").unwrap();
+    let mut c = open_reader(Cursor::new(data)).unwrap();
+    let parameters = c.streams().best(Type::Audio).unwrap().parameters();
+
+    // 格式上下文、流参数和解码出的帧都可以在线程间移动
+    let handle = std::thread::spawn(move || {
+        let index = c.streams().best(Type::Audio).unwrap().index();
+        c.decode_audio(index).unwrap().map(|frame| frame.unwrap()).collect::<Vec<_>>()
+    });
+    let frames = handle.join().unwrap();
+    assert!(!frames.is_empty());
+    assert!(parameters.codec_id() == ffmpeg_di::codec::codec_id::CodecId::PCM_U8);
+}
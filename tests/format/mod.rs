@@ -1 +1,6 @@
-mod input_tests;
\ No newline at end of file
+mod input_tests;
+mod output_tests;
+mod packet_tests;
+mod streaming_tests;
+#[cfg(feature = "tokio")]
+mod async_input_tests;
\ No newline at end of file
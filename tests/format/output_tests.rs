@@ -0,0 +1,123 @@
+use std::fs;
+
+use ffmpeg_di::codec::codec_id::CodecId;
+use ffmpeg_di::codec::codec_par::Parameters;
+use ffmpeg_di::codec::context::{find_encoder, Context};
+use ffmpeg_di::format::input::open;
+use ffmpeg_di::format::output::{create, find_output_format, guess_output_format, muxers};
+use ffmpeg_di::format::packet::Packet;
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::dict::Dictionary;
+use ffmpeg_di::util::frame;
+use ffmpeg_di::util::media;
+use ffmpeg_di::util::samplefmt::SampleFormat;
+use ffmpeg_di::Error;
+
+#[test]
+fn test_find_output_format() {
+    let f = find_output_format("wav").expect("no such output format: wav");
+    assert_eq!(f.name(), "wav");
+    assert!(f.long_name().contains("WAV"));
+    assert!(f.audio_codec() == CodecId::PCM_S16LE);
+    assert!(find_output_format("wav\0").is_none());
+}
+
+#[test]
+fn test_create_output() {
+    let path = std::env::temp_dir().join("ffmpeg_di_create_output.wav");
+    let mut c = create(path.to_str().unwrap(), None).unwrap();
+    assert_eq!(c.format().name(), "wav");
+
+    c.add_stream().unwrap();
+    assert_eq!(c.nb_streams(), 1);
+
+    drop(c);
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(create("out\0.wav", None), Err(Error::InvalidArgument)));
+}
+
+#[test]
+fn test_output_on_other_thread() {
+    let path = std::env::temp_dir().join("ffmpeg_di_output_thread.wav");
+    let c = create(path.to_str().unwrap(), None).unwrap();
+    let format = find_output_format("wav").unwrap();
+
+    // 输出上下文及封装格式都可以在线程间移动
+    let handle = std::thread::spawn(move || {
+        assert_eq!(format.name(), c.format().name());
+        drop(c);
+    });
+    handle.join().unwrap();
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write_wav() {
+    let path = std::env::temp_dir().join("ffmpeg_di_write_wav.wav");
+    let filename = path.to_str().unwrap();
+
+    let codec = find_encoder(CodecId::PCM_S16LE).unwrap();
+    let mut audio = Context::new_with_codec(&codec).encoder().audio().unwrap();
+    audio.set_rate(16000);
+    audio.set_format(SampleFormat::S16);
+    audio.set_channel_layout(ChannelLayout::default(1));
+    audio.set_time_base((1, 16000));
+    let mut encoder = audio.open_as_with(codec, Dictionary::new()).unwrap();
+
+    let mut c = create(filename, None).unwrap();
+    {
+        let mut stream = c.add_stream().unwrap();
+        stream.set_codecpar(Parameters::from_context(&encoder).unwrap());
+        stream.set_time_base((1, 16000));
+    }
+    c.write_header(Dictionary::new()).unwrap();
+    let time_base = c.stream_mut(0).unwrap().time_base();
+
+    let samples: Vec<i16> = (0..1600).map(|i| (i * 20) as i16).collect();
+    let mut frame = frame::Audio::from_interleaved(&samples, ChannelLayout::default(1), 16000).unwrap();
+    encoder.send_frame(&frame).unwrap();
+    frame.set_pts(Some(1600));
+    encoder.send_frame(&frame).unwrap();
+    encoder.send_eof().unwrap();
+
+    let mut packet = Packet::empty();
+    let mut written = 0;
+    while encoder.receive_packet(&mut packet).is_ok() {
+        packet.set_stream_index(0);
+        packet.rescale_ts((1, 16000), time_base);
+        // 第一个包直接写入，其余由封装器交织
+        if written == 0 {
+            c.write_packet(&mut packet).unwrap();
+        } else {
+            c.write_interleaved(&mut packet).unwrap();
+        }
+        written += 1;
+    }
+    assert_eq!(written, 2);
+    c.write_trailer().unwrap();
+    drop(c);
+
+    // 重新打开，检查写入的参数和采样数
+    let mut input = open(filename).unwrap();
+    let parameters = input.streams().best(media::Type::Audio).unwrap().parameters();
+    assert!(parameters.codec_id() == CodecId::PCM_S16LE);
+    assert_eq!(parameters.rate(), 16000);
+    assert_eq!(parameters.channels(), 1);
+
+    let mut decoded = 0;
+    for frame in input.decode_audio(0).unwrap() {
+        decoded += frame.unwrap().samples();
+    }
+    assert_eq!(decoded, 3200);
+
+    drop(input);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_guess_output_format() {
+    let f = guess_output_format(Some("out.wav"), None).unwrap();
+    assert_eq!(f.name(), "wav");
+    assert!(guess_output_format(None, Some("audio/x-wav")).is_some());
+    assert!(muxers().any(|f| f.name() == "wav"));
+}
@@ -0,0 +1,40 @@
+use ffmpeg_di::format::packet::side_data_type::PacketSideDataType;
+use ffmpeg_di::format::packet::{Flags, Packet};
+use ffmpeg_di::util::rational::Rational;
+
+#[test]
+fn test_packet_copy_and_clone() {
+    let mut pkt = Packet::copy(&[1, 2, 3, 4]);
+    pkt.set_pts(Some(100));
+    pkt.set_dts(Some(100));
+    pkt.set_duration(10);
+    pkt.set_flags(Flags::KEY);
+
+    let mut cloned = pkt.clone();
+    assert_eq!(cloned.data(), &[1, 2, 3, 4]);
+    assert_eq!(cloned.pts(), Some(100));
+    assert!(cloned.is_key());
+
+    cloned.data_mut().unwrap()[0] = 9;
+    assert_eq!(pkt.data()[0], 1);
+}
+
+#[test]
+fn test_packet_rescale_ts() {
+    let mut pkt = Packet::empty();
+    pkt.set_pts(Some(16000));
+    pkt.set_dts(None);
+    pkt.rescale_ts(Rational::new(1, 16000), Rational::new(1, 1000));
+
+    assert_eq!(pkt.pts(), Some(1000));
+    assert_eq!(pkt.dts(), None);
+}
+
+#[test]
+fn test_packet_side_data() {
+    let mut pkt = Packet::copy(&[0; 8]);
+    pkt.add_side_data(PacketSideDataType::SkipSamples, &[0; 10]).unwrap();
+
+    let kinds: Vec<_> = pkt.side_data().map(|sd| sd.kind()).collect();
+    assert_eq!(kinds, vec![PacketSideDataType::SkipSamples]);
+}
@@ -0,0 +1,41 @@
+use ffmpeg_di::format::streaming::{Decoded, StreamingInput};
+
+fn decode_in_chunks(mut input: StreamingInput, chunk_size: usize) -> i32 {
+    let data = std::fs::read("tests/assets/snd_u8.wav").unwrap();
+    let mut samples = 0;
+
+    for chunk in data.chunks(chunk_size) {
+        input.push(chunk);
+        while let Decoded::Frame(frame) = input.receive().unwrap() {
+            samples += frame.samples();
+        }
+    }
+
+    input.end();
+    loop {
+        match input.receive().unwrap() {
+            Decoded::Frame(frame) => samples += frame.samples(),
+            Decoded::NeedMoreData => panic!("need more data after end"),
+            Decoded::Finished => break,
+        }
+    }
+
+    samples
+}
+
+#[test]
+fn test_streaming_input() {
+    assert_eq!(decode_in_chunks(StreamingInput::new(), 1000), 3607479);
+}
+
+#[test]
+fn test_streaming_input_split_packets() {
+    // 不预留余量，wav 的 4096 字节数据包会跨越缓存末尾，需要回退重读而不能截断或提前结束
+    let mut input = StreamingInput::new();
+    input.set_read_ahead(0);
+    assert_eq!(decode_in_chunks(input, 1000), 3607479);
+
+    let mut input = StreamingInput::new();
+    input.set_read_ahead(0);
+    assert_eq!(decode_in_chunks(input, 333), 3607479);
+}
@@ -1,3 +1,5 @@
 mod codec;
+mod filter;
 mod format;
+mod software;
 mod util;
\ No newline at end of file
@@ -0,0 +1 @@
+mod resampling_tests;
\ No newline at end of file
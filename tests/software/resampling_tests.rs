@@ -0,0 +1,44 @@
+use ffmpeg_di::format::input::open;
+use ffmpeg_di::software::resampling::Context;
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::media;
+use ffmpeg_di::util::samplefmt::SampleFormat;
+
+#[test]
+fn test_resample() {
+    let mut c = open("tests/assets/snd_u8.wav").unwrap();
+    let index = c.streams().best(media::Type::Audio).unwrap().index();
+
+    let mut resampler = Context::get(
+        SampleFormat::U8,
+        ChannelLayout::default(1),
+        16000,
+        SampleFormat::FLT,
+        ChannelLayout::default(2),
+        8000,
+    )
+    .unwrap();
+
+    let mut input = 0i64;
+    let mut output = 0i64;
+    for frame in c.decode_audio(index).unwrap() {
+        let frame = frame.unwrap();
+        input += frame.samples() as i64;
+
+        let converted = resampler.convert(&frame).unwrap();
+        assert!(converted.format() == SampleFormat::FLT);
+        assert_eq!(converted.channels(), 2);
+        assert_eq!(converted.sample_rate(), 8000);
+        output += converted.samples() as i64;
+    }
+    assert_eq!(input, 3607479);
+
+    // 已输出的加上内部缓存的，即为全部输入对应的输出采样数
+    let expected = input / 2;
+    assert!((output + resampler.delay() - expected).abs() <= 1);
+
+    while let Some(rest) = resampler.flush().unwrap() {
+        output += rest.samples() as i64;
+    }
+    assert!((output - expected).abs() <= 1);
+}
@@ -1,7 +1,46 @@
-use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::ffi;
+use ffmpeg_di::util::channel_layout::{Channel, ChannelLayout, ChannelOrder};
 
 #[test]
 pub fn test_channel_layout_default() {
     let c = ChannelLayout::default(2);
-    println!("{:?}", c.nb_channels())
-}
\ No newline at end of file
+    assert_eq!(c.nb_channels(), 2);
+    assert_eq!(c.order(), ChannelOrder::Native);
+    assert_eq!(c.describe(), "stereo");
+}
+
+#[test]
+pub fn test_channel_layout_from_mask_and_string() {
+    let mask = ChannelLayout::from_mask(ffi::AV_CH_LAYOUT_5POINT1).unwrap();
+    let string = ChannelLayout::from_string("5.1(side)").unwrap();
+    assert_eq!(mask, string);
+    assert_eq!(mask.mask(), Some(ffi::AV_CH_LAYOUT_5POINT1));
+    assert_eq!(mask.clone(), mask);
+    assert_ne!(mask, ChannelLayout::default(2));
+
+    assert!(ChannelLayout::from_string("not a layout").is_err());
+    assert!(ChannelLayout::from_mask(0).is_err());
+}
+
+#[test]
+pub fn test_channel_layout_channels() {
+    let c = ChannelLayout::from_string("5.1(side)").unwrap();
+    assert_eq!(
+        c.channels(),
+        vec![
+            Channel::FrontLeft,
+            Channel::FrontRight,
+            Channel::FrontCenter,
+            Channel::LowFrequency,
+            Channel::SideLeft,
+            Channel::SideRight,
+        ]
+    );
+    assert_eq!(c.index_of(Channel::LowFrequency), Some(3));
+    assert_eq!(c.index_of(Channel::BackLeft), None);
+    assert_eq!(c.channel(6), None);
+
+    let custom = ChannelLayout::from_string("FR+FL").unwrap();
+    assert_eq!(custom.order(), ChannelOrder::Custom);
+    assert_eq!(custom.channel(0), Some(Channel::FrontRight));
+}
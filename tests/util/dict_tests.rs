@@ -0,0 +1,21 @@
+use ffmpeg_di::util::dict::Dictionary;
+use ffmpeg_di::Error;
+
+#[test]
+pub fn test_dict_set_get() {
+    let mut dict = Dictionary::new();
+    dict.set("sample_rate", "16000").unwrap();
+
+    assert_eq!(dict.get("sample_rate"), Some("16000"));
+    assert_eq!(dict.get("channels"), None);
+}
+
+#[test]
+pub fn test_dict_nul() {
+    let mut dict = Dictionary::new();
+
+    // 含有 NUL 的键或值返回错误而不是 panic
+    assert_eq!(dict.set("a\0b", "1"), Err(Error::InvalidArgument));
+    assert_eq!(dict.set("a", "1\0"), Err(Error::InvalidArgument));
+    assert_eq!(dict.get("a\0b"), None);
+}
@@ -0,0 +1,24 @@
+use ffmpeg_di::ffi;
+use ffmpeg_di::Error;
+
+#[test]
+pub fn test_error_from_code() {
+    assert_eq!(Error::from(ffi::AVERROR_EOF), Error::Eof);
+    assert_eq!(Error::from(ffi::AVERROR(libc::EAGAIN)), Error::Again);
+    assert_eq!(Error::from(ffi::AVERROR_INVALIDDATA), Error::InvalidData);
+    assert_eq!(Error::from(-123456), Error::Other(-123456));
+}
+
+#[test]
+pub fn test_error_code_round_trip() {
+    for e in [Error::Again, Error::Eof, Error::InvalidData, Error::DecoderNotFound, Error::Io] {
+        assert_eq!(Error::from(e.code()), e);
+    }
+}
+
+#[test]
+pub fn test_error_display() {
+    assert_eq!(Error::Eof.to_string(), "End of file");
+    assert_eq!(Error::InvalidData.to_string(), "Invalid data found when processing input");
+    assert_eq!(Error::NotOpen.to_string(), "codec context not open");
+}
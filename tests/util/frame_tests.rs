@@ -0,0 +1,80 @@
+use ffmpeg_di::codec::context::Context;
+use ffmpeg_di::format::input::open;
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::{frame, media};
+use ffmpeg_di::util::samplefmt::SampleFormat;
+
+fn decode_first_frame() -> frame::Audio {
+    let mut c = open("tests/assets/snd_u8.wav").unwrap();
+    let stream = c.streams().best(media::Type::Audio).unwrap();
+    let index = stream.index() as i32;
+    let mut decoder = Context::parameters_to_context(stream.parameters()).unwrap().decoder().audio().unwrap();
+
+    let mut decoded = frame::Audio::empty();
+    for item in c.packets() {
+        let (_, packet) = item.unwrap();
+        if packet.stream_index() != index {
+            continue;
+        }
+        decoder.send_packet(&packet).unwrap();
+        if decoder.receive_frame(&mut decoded).is_ok() {
+            break;
+        }
+    }
+    decoded
+}
+
+#[test]
+pub fn test_audio_plane() {
+    let mut decoded = decode_first_frame();
+    assert!(decoded.is_packed());
+    let len = decoded.plane::<u8>(0).len();
+    assert_eq!(len, (decoded.samples() * decoded.channels()) as usize);
+
+    decoded.plane_mut::<u8>(0)[0] = 128;
+    assert_eq!(decoded.plane::<u8>(0)[0], 128);
+}
+
+#[test]
+pub fn test_audio_convert() {
+    let decoded = decode_first_frame();
+    let original = decoded.plane::<u8>(0).to_vec();
+
+    let planar = decoded.convert(SampleFormat::DBLP).unwrap();
+    assert!(planar.format() == SampleFormat::DBLP);
+    assert_eq!(planar.samples(), decoded.samples());
+    assert_eq!(planar.sample_rate(), decoded.sample_rate());
+
+    let back = planar.convert(SampleFormat::U8).unwrap();
+    assert_eq!(back.plane::<u8>(0), original.as_slice());
+
+    let interleaved = decoded.to_interleaved::<f32>();
+    assert_eq!(interleaved.len(), original.len());
+    assert!(interleaved.iter().all(|s| (-1.0..=1.0).contains(s)));
+
+    let planes = decoded.to_planar::<i16>();
+    assert_eq!(planes.len(), decoded.channels() as usize);
+    assert_eq!(planes[0].len(), decoded.samples() as usize);
+    assert_eq!(planes[0][0], (original[0] as i16 - 128) << 8);
+}
+
+#[test]
+pub fn test_audio_from_samples() {
+    let data: Vec<i16> = (0..200).collect();
+    let frame = frame::Audio::from_interleaved(&data, ChannelLayout::default(2), 16000).unwrap();
+    assert!(frame.format() == SampleFormat::S16);
+    assert_eq!(frame.samples(), 100);
+    assert_eq!(frame.sample_rate(), 16000);
+    assert_eq!(frame.pts(), Some(0));
+    assert_eq!(frame.plane::<i16>(0), data.as_slice());
+
+    let left = vec![0.5f32; 160];
+    let right = vec![-0.5f32; 160];
+    let frame = frame::Audio::from_planes(&[&left, &right], ChannelLayout::default(2), 16000).unwrap();
+    assert!(frame.format() == SampleFormat::FLTP);
+    assert_eq!(frame.plane::<f32>(1), right.as_slice());
+    assert_eq!(frame.to_interleaved::<f32>()[..2], [0.5, -0.5]);
+
+    assert!(frame::Audio::from_interleaved(&data[..199], ChannelLayout::default(2), 16000).is_err());
+    assert!(frame::Audio::from_planes(&[&left[..], &right[..10]], ChannelLayout::default(2), 16000).is_err());
+}
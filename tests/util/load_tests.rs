@@ -0,0 +1,38 @@
+use std::fs::File;
+use std::time::Duration;
+
+use ffmpeg_di::codec::codec_id::CodecId;
+use ffmpeg_di::util::samplefmt::SampleFormat;
+use ffmpeg_di::util::{load_audio, load_audio_reader, LoadOptions};
+
+#[test]
+pub fn test_load_audio() {
+    let loaded = load_audio("tests/assets/snd_u8.wav", LoadOptions::default()).unwrap();
+    assert!(loaded.codec == CodecId::PCM_U8);
+    assert_eq!(loaded.source_rate, 16000);
+    assert_eq!(loaded.source_channels, 1);
+    assert!(loaded.source_format == SampleFormat::U8);
+
+    assert_eq!(loaded.rate(), 16000);
+    assert_eq!(loaded.channels(), 1);
+    assert_eq!(loaded.samples(), 3607479);
+    assert!(loaded.frame.format() == SampleFormat::FLT);
+}
+
+#[test]
+pub fn test_load_audio_window() {
+    let options = LoadOptions {
+        rate: Some(8000),
+        channels: Some(2),
+        format: SampleFormat::S16P,
+        start: Some(Duration::from_secs(1)),
+        duration: Some(Duration::from_millis(500)),
+    };
+    let file = File::open("tests/assets/snd_u8.wav").unwrap();
+    let loaded = load_audio_reader(file, options).unwrap();
+    assert_eq!(loaded.rate(), 8000);
+    assert_eq!(loaded.channels(), 2);
+    assert_eq!(loaded.samples(), 4000);
+    assert_eq!(loaded.duration(), Duration::from_millis(500));
+    assert_eq!(loaded.to_interleaved::<i16>().len(), 8000);
+}
@@ -1 +1,7 @@
-mod channel_layout_tests;
\ No newline at end of file
+mod channel_layout_tests;
+mod dict_tests;
+mod error_tests;
+mod frame_tests;
+mod load_tests;
+mod options_tests;
+mod rational_tests;
\ No newline at end of file
@@ -0,0 +1,37 @@
+use ffmpeg_di::codec::codec_id::CodecId;
+use ffmpeg_di::codec::context::{find_encoder, Context};
+use ffmpeg_di::util::channel_layout::ChannelLayout;
+use ffmpeg_di::util::options::{OptionType, Options};
+use ffmpeg_di::Error;
+
+#[test]
+fn test_set_get() {
+    let mut c = Context::new();
+    c.set("b", "64000").unwrap();
+    assert_eq!(c.get("b").unwrap(), "64000");
+
+    c.set_int("ar", 16000).unwrap();
+    assert_eq!(c.get("ar").unwrap(), "16000");
+
+    c.set_channel_layout("ch_layout", &ChannelLayout::default(2)).unwrap();
+    assert_eq!(c.get("ch_layout").unwrap(), "stereo");
+
+    assert!(matches!(c.set("no_such_option", "1"), Err(Error::OptionNotFound)));
+    assert!(matches!(c.get("no_such_option"), Err(Error::OptionNotFound)));
+}
+
+#[test]
+fn test_private_options() {
+    let codec = find_encoder(CodecId::AAC).unwrap();
+    let mut c = Context::new_with_codec(&codec);
+
+    let options = c.options();
+    let bit_rate = options.iter().find(|o| o.name == "b").unwrap();
+    assert!(bit_rate.kind == OptionType::Int64);
+
+    let coder = options.iter().find(|o| o.name == "aac_coder").unwrap();
+    assert!(coder.constants.iter().any(|name| name == "fast"));
+
+    c.set("aac_coder", "fast").unwrap();
+    assert_eq!(c.get("aac_coder").unwrap(), "2");
+}
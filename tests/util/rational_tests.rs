@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use ffmpeg_di::util::rational::{rescale, rescale_rnd, Rational, Rounding};
+use ffmpeg_di::util::timestamp::Timestamp;
+
+#[test]
+pub fn test_rational_arithmetic() {
+    let half = Rational::new(1, 2);
+    let third = Rational::new(1, 3);
+
+    assert_eq!(half + third, Rational::new(5, 6));
+    assert_eq!(half - third, Rational::new(1, 6));
+    assert_eq!(half * third, Rational::new(1, 6));
+    assert_eq!(half / third, Rational::new(3, 2));
+    assert_eq!(third.invert(), Rational::new(3, 1));
+    assert_eq!(half, Rational::new(2, 4));
+    assert!(third < half);
+}
+
+#[test]
+pub fn test_rational_order() {
+    let nan = Rational::new(0, 0);
+    let zero = Rational::new(0, 5);
+
+    // 0/0 与自身相等，排在其他值之后，不影响其余值之间的相等关系
+    assert_eq!(nan, Rational::new(0, 0));
+    assert!(nan > Rational::new(1, 0));
+    assert!(nan > Rational::new(-1, 2));
+    assert_eq!(zero, Rational::new(0, -3));
+    assert!(zero < nan && Rational::new(0, -3) < nan);
+    assert!(Rational::new(1, -2) < Rational::new(1, 2));
+}
+
+#[test]
+pub fn test_rescale() {
+    assert_eq!(rescale(16000, (1, 16000), (1, 1000)), 1000);
+    assert_eq!(rescale(3, (1, 2), (1, 1)), 2);
+    assert_eq!(rescale_rnd(3, (1, 2), (1, 1), Rounding::Down), 1);
+    assert_eq!(rescale_rnd(3, (1, 2), (1, 1), Rounding::Up), 2);
+}
+
+#[test]
+pub fn test_timestamp() {
+    let ts = Timestamp::new(24000, (1, 16000));
+    assert_eq!(ts.as_secs_f64(), 1.5);
+    assert_eq!(ts.as_duration(), Some(Duration::from_millis(1500)));
+    assert_eq!(ts.rescale((1, 1000)).value, 1500);
+    assert_eq!(ts, Timestamp::from_duration(Duration::from_millis(1500), (1, 1000)));
+    assert!(Timestamp::new(-1, (1, 1000)).as_duration().is_none());
+}